@@ -0,0 +1,80 @@
+//! Compares the time and allocations of reading a form's results by borrowing and by copying.
+//!
+//! Run with `cargo bench`.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::borrow::Cow;
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use tui_form_widget::{FieldDef, Form};
+
+/// Counts allocations made through the global allocator.
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+const ITERATIONS: u32 = 1_000;
+
+/// Runs `f` `ITERATIONS` times, returning the time and allocations per run.
+fn measure(f: impl Fn()) -> (Duration, usize) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let elapsed = start.elapsed() / ITERATIONS;
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations;
+    (elapsed, allocations / ITERATIONS as usize)
+}
+
+fn report(name: &str, f: impl Fn()) {
+    let (elapsed, allocations) = measure(f);
+    println!("{name:<32} {elapsed:>12?} {allocations:>6} allocations");
+}
+
+fn main() {
+    // a form of large multiline values, like pasted config files
+    let value = "key = \"value\"\n".repeat(1_000);
+    let form = Form::from(
+        (0..20)
+            .map(|i| FieldDef::textarea(&format!("Field {i}"), 5).value(&value))
+            .collect::<Vec<_>>(),
+    );
+    // validate once up front, like after the first frame
+    form.status();
+
+    report("status, copying values", || {
+        let values: Vec<String> = form.status().into_iter().map(String::from).collect();
+        black_box(values);
+    });
+    report("status, borrowing values", || {
+        let values: Vec<Cow<str>> = form.status().into_iter().map(Cow::from).collect();
+        black_box(values);
+    });
+    report("iter_fields, borrowing values", || {
+        let values: Vec<&str> = form.iter_fields().map(|field| field.value()).collect();
+        black_box(values);
+    });
+    report("field, copying value", || {
+        black_box(form.field(10).map(String::from));
+    });
+    report("field, borrowing value", || {
+        black_box(form.field(10).map(|field| field.value()));
+    });
+}
@@ -10,18 +10,24 @@ use ratatui::{prelude::*, widgets::*};
 
 struct State {
     form: Form,
-    submissions: Option<Vec<String>>,
+    submissions: Option<Vec<SubmittedField>>,
     should_quit: bool,
 }
 
-use tui_form_widget::{Form, FormSelection};
+use tui_form_widget::{FieldDef, Form, FormSelection, SubmittedField};
 
 fn main() -> io::Result<()> {
     let mut state = State {
-        form: Form::from(vec!["Account", "Username / Email", "Password"]),
+        form: Form::from(vec![
+            FieldDef::text("Account"),
+            FieldDef::text("Username / Email"),
+            FieldDef::text("Password").secret(),
+        ]),
         should_quit: false,
         submissions: None,
     };
+    state.form.show_hints(true);
+    state.form.hints(&[("s", "submit"), ("q", "quit")]);
     let mut terminal = setup_terminal()?;
     run(&mut terminal, &mut state)?;
     restore_terminal(&mut terminal)?;
@@ -54,7 +60,13 @@ fn run(terminal: &mut Terminal<CrosstermBackend<Stdout>>, state: &mut State) ->
 
 fn render_app(frame: &mut Frame<CrosstermBackend<Stdout>>, state: &State) {
     match &state.submissions {
-        Some(fields) => frame.render_widget(Paragraph::new(fields.join("\n")), frame.size()),
+        Some(fields) => {
+            let lines: Vec<Line> = fields
+                .iter()
+                .map(|f| Line::from(format!("{}: {}", f.name(), f.redacted_value())))
+                .collect();
+            frame.render_widget(Paragraph::new(lines), frame.size())
+        }
         None => frame.render_widget(state.form.widget(), frame.size()),
     }
 }
@@ -62,22 +74,15 @@ fn render_app(frame: &mut Frame<CrosstermBackend<Stdout>>, state: &State) {
 fn handle_input(state: &mut State) -> io::Result<()> {
     if event::poll(Duration::from_millis(250))? {
         if let Event::Key(key) = event::read()? {
-            match state.form.selected() {
-                FormSelection::NoSelection => match key.code {
+            if *state.form.selected() == FormSelection::NoSelection {
+                match key.code {
                     KeyCode::Esc | KeyCode::Char('q') => state.should_quit = true,
-                    KeyCode::Char('s') => {
-                        let fields = state.form.submit();
-                        if fields.iter().any(|f| !f.is_valid()) {
-                        } else {
-                            // Field impls Into<String>
-                            state.submissions = Some(fields.into_iter().map(Into::into).collect());
-
-                            state.form.deselect();
-                        }
+                    KeyCode::Char('s') if state.form.submit().iter().all(|f| f.is_valid()) => {
+                        let form = std::mem::take(&mut state.form);
+                        state.submissions = Some(form.into_values());
                     }
                     _ => {}
-                },
-                _ => {}
+                }
             }
 
             state.form.input(key.code);
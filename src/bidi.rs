@@ -0,0 +1,108 @@
+//! Rendering text containing right-to-left scripts, like Arabic or Hebrew, in visual order.
+
+use ratatui::prelude::*;
+use unicode_bidi::{bidi_class, BidiClass, BidiInfo};
+
+use crate::text::TextBuffer;
+use crate::widget::TextStyle;
+
+/// Returns `true` if `text` contains any right-to-left chars, so it needs to be reordered before
+/// it's rendered.
+pub(crate) fn has_rtl(text: &TextBuffer) -> bool {
+    text.before()
+        .chars()
+        .chain(text.after())
+        .any(|ch| matches!(bidi_class(ch), BidiClass::R | BidiClass::AL))
+}
+
+/// Writes `text` into `area` like `write_text`, but with each line reordered for display using the
+/// Unicode bidirectional algorithm. Lines starting with right-to-left text are right aligned.
+/// Returns the position of the cursor, which is on the char after it in logical order.
+pub(crate) fn write_text(
+    buf: &mut Buffer,
+    area: Rect,
+    text: &TextBuffer,
+    style: TextStyle<'_>,
+) -> Option<(u16, u16)> {
+    let cursor = text.before().len();
+    let cursor_line = text.before().matches('\n').count();
+    let first_line = cursor_line.saturating_sub(area.height as usize - 1);
+    let width = area.width as usize;
+
+    let mut position = None;
+    let mut line_start = 0;
+    for (i, line) in text.as_str().split('\n').enumerate() {
+        let start = line_start;
+        line_start += line.len() + 1;
+        let Some(row) = i.checked_sub(first_line) else {
+            continue;
+        };
+        if row >= area.height as usize {
+            break;
+        }
+
+        let (slots, rtl) = visual_order(line);
+        // the cursor sits on the slot of the char after it, or the empty slot past the end
+        let cursor_slot = (i == cursor_line).then(|| {
+            slots
+                .iter()
+                .position(|slot| matches!(slot, Some((b, _)) if start + b == cursor))
+                .unwrap_or(if rtl { 0 } else { slots.len() - 1 })
+        });
+
+        let (first, x) = if rtl {
+            let first = slots.len().saturating_sub(width);
+            let first = cursor_slot.map_or(first, |c| first.min(c));
+            (
+                first,
+                area.right() - (slots.len() - first).min(width) as u16,
+            )
+        } else {
+            let first = cursor_slot.map_or(0, |c| c.saturating_sub(width - 1));
+            (first, area.x)
+        };
+
+        let y = area.y + row as u16;
+        for (col, slot) in slots.iter().enumerate().skip(first).take(width) {
+            let x = x + (col - first) as u16;
+            if let Some((_, ch)) = slot {
+                let display = style.display(*ch);
+                buf.set_stringn(x, y, display.encode_utf8(&mut [0; 4]), 1, style.char(*ch));
+            }
+            if Some(col) == cursor_slot {
+                position = Some((x, y));
+            }
+        }
+    }
+    position
+}
+
+/// Returns the chars of `line` in visual order along with their byte index in `line`, and whether
+/// the line is right-to-left. An empty slot is added past the logical end of the line, on the right
+/// for left-to-right lines and on the left for right-to-left lines.
+fn visual_order(line: &str) -> (Vec<Option<(usize, char)>>, bool) {
+    let info = BidiInfo::new(line, None);
+    let rtl = info.paragraphs.first().is_some_and(|p| p.level.is_rtl());
+
+    let mut slots = Vec::with_capacity(line.len() + 1);
+    if rtl {
+        slots.push(None);
+    }
+    for para in &info.paragraphs {
+        let (levels, runs) = info.visual_runs(para, para.range.clone());
+        for run in runs {
+            let chars = line[run.clone()]
+                .char_indices()
+                .map(|(i, ch)| Some((run.start + i, ch)));
+            if levels[run.start].is_rtl() {
+                slots.extend(chars.rev());
+            } else {
+                slots.extend(chars);
+            }
+        }
+    }
+    if !rtl {
+        slots.push(None);
+    }
+    (slots, rtl)
+}
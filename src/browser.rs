@@ -0,0 +1,155 @@
+//! Browsing the file system to fill in path fields.
+
+use std::path::{Path, PathBuf};
+
+use crossterm::event::KeyCode;
+
+/// Outcome of a key pressed while browsing.
+#[derive(Debug, PartialEq)]
+pub(crate) enum BrowserEvent {
+    Handled,
+    /// A path was picked for the field.
+    Selected(String),
+    Closed,
+}
+
+/// A popup listing the entries of a directory, opened from a path field with
+/// [`Action::Browse`](crate::Action::Browse).
+///
+/// The first two entries select the directory itself and go up to its parent. Typing filters the
+/// other entries by name.
+#[derive(Clone, Debug)]
+pub(crate) struct Browser {
+    /// Directory being listed, empty for the current directory.
+    dir: PathBuf,
+    /// Names of the directory's entries, and whether they're directories, directories first.
+    entries: Vec<(String, bool)>,
+    filter: String,
+    /// Labels of the entries shown, along with the entries' paths and whether they're directories.
+    pub(crate) labels: Vec<String>,
+    paths: Vec<(PathBuf, bool)>,
+    pub(crate) highlighted: usize,
+}
+
+impl Browser {
+    /// Opens the directory of the path in `val`, or the current directory if there isn't one.
+    pub(crate) fn open(val: &str) -> Self {
+        let path = Path::new(val);
+        let dir = if path.is_dir() {
+            path
+        } else {
+            path.parent()
+                .filter(|dir| dir.is_dir())
+                .unwrap_or(Path::new(""))
+        };
+        let mut browser = Self {
+            dir: dir.to_path_buf(),
+            entries: Vec::new(),
+            filter: String::new(),
+            labels: Vec::new(),
+            paths: Vec::new(),
+            highlighted: 0,
+        };
+        browser.read_dir();
+        browser
+    }
+
+    /// Title of the popup, the directory followed by the filter.
+    pub(crate) fn title(&self) -> String {
+        let dir = self.dir.to_string_lossy();
+        match dir.is_empty() {
+            true => format!("./{}", self.filter),
+            false => format!("{}/{}", dir.trim_end_matches('/'), self.filter),
+        }
+    }
+
+    pub(crate) fn input(&mut self, key: KeyCode) -> BrowserEvent {
+        match key {
+            KeyCode::Up => self.highlighted = self.highlighted.saturating_sub(1),
+            KeyCode::Down => self.highlighted = (self.highlighted + 1).min(self.labels.len() - 1),
+            KeyCode::Enter | KeyCode::Right => {
+                let (path, dir) = self.paths[self.highlighted].clone();
+                let select = self.highlighted == 0 || !dir;
+                match (select, key) {
+                    (true, KeyCode::Enter) => {
+                        let path = path.to_string_lossy();
+                        let path = if path.is_empty() { "." } else { &path };
+                        return BrowserEvent::Selected(path.to_string());
+                    }
+                    (true, _) => {}
+                    (false, _) => self.enter(path),
+                }
+            }
+            KeyCode::Left => self.enter(self.parent()),
+            KeyCode::Backspace if self.filter.is_empty() => self.enter(self.parent()),
+            KeyCode::Backspace => {
+                self.filter.pop();
+                self.refresh();
+            }
+            KeyCode::Char(ch) => {
+                self.filter.push(ch);
+                self.refresh();
+                // highlight the first match
+                self.highlighted = 2.min(self.labels.len() - 1);
+            }
+            KeyCode::Esc | KeyCode::Tab => return BrowserEvent::Closed,
+            _ => {}
+        }
+        BrowserEvent::Handled
+    }
+
+    fn parent(&self) -> PathBuf {
+        match self.dir.file_name() {
+            Some(_) => self.dir.parent().unwrap_or(Path::new("")).to_path_buf(),
+            None => self.dir.join(".."),
+        }
+    }
+
+    fn enter(&mut self, dir: PathBuf) {
+        self.dir = dir;
+        self.filter.clear();
+        self.highlighted = 0;
+        self.read_dir();
+    }
+
+    /// Lists the entries of the directory. Entries that can't be read are skipped.
+    fn read_dir(&mut self) {
+        let dir = match self.dir.as_os_str().is_empty() {
+            true => Path::new("."),
+            false => &self.dir,
+        };
+        self.entries = std::fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| {
+                let dir = entry.path().is_dir();
+                (entry.file_name().to_string_lossy().into_owned(), dir)
+            })
+            .collect();
+        self.entries.sort_by(|(a, a_dir), (b, b_dir)| {
+            b_dir
+                .cmp(a_dir)
+                .then_with(|| a.to_lowercase().cmp(&b.to_lowercase()))
+        });
+        self.refresh();
+    }
+
+    /// Updates the entries shown to those matching the filter.
+    fn refresh(&mut self) {
+        let filter = self.filter.to_lowercase();
+        self.labels = vec!["./".to_string(), "../".to_string()];
+        self.paths = vec![(self.dir.clone(), true), (self.parent(), true)];
+        for (name, dir) in &self.entries {
+            if !name.to_lowercase().contains(&filter) {
+                continue;
+            }
+            self.labels.push(match dir {
+                true => format!("{name}/"),
+                false => name.clone(),
+            });
+            self.paths.push((self.dir.join(name), *dir));
+        }
+        self.highlighted = self.highlighted.min(self.labels.len() - 1);
+    }
+}
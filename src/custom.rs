@@ -0,0 +1,114 @@
+//! Custom field kinds implemented by applications.
+
+use std::fmt;
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::prelude::*;
+
+use crate::FormEvent;
+
+/// A custom kind of field, e.g. a field capturing a key binding, added to a form with
+/// [`FieldDef::custom`](crate::FieldDef::custom).
+///
+/// The form draws the field's border, title and validation messages and handles focus, while the
+/// widget handles keys and draws the content of the field. Its [`value`](FieldWidget::value) is
+/// used like the value of any other field, for validation and the form's results.
+///
+/// With the `tui-textarea` feature, `tui_textarea::TextArea` implements this trait, so it can be
+/// used as a multiline field. Likewise with the `tui-input` feature, `tui_input::Input` can be
+/// used as a single-line field.
+///
+/// # Example
+///
+/// ```
+/// # use crossterm::event::KeyCode;
+/// # use ratatui::prelude::*;
+/// # use tui_form_widget::{FieldDef, FieldWidget, Form, FormEvent, FormSelection};
+/// /// Captures the next key pressed.
+/// #[derive(Clone, Default)]
+/// struct KeyCapture(Option<KeyCode>);
+///
+/// impl FieldWidget for KeyCapture {
+///     fn handle_input(&mut self, key: KeyCode) -> FormEvent {
+///         match key {
+///             KeyCode::Esc => FormEvent::Ignored,
+///             key => {
+///                 self.0 = Some(key);
+///                 FormEvent::Handled
+///             }
+///         }
+///     }
+///
+///     fn render(&self, area: Rect, buf: &mut Buffer, _active: bool) {
+///         buf.set_stringn(area.x, area.y, self.value(), area.width as usize, Style::default());
+///     }
+///
+///     fn value(&self) -> String {
+///         self.0.map(|key| format!("{key:?}")).unwrap_or_default()
+///     }
+/// }
+///
+/// let mut form = Form::from(vec![FieldDef::custom("Quit key", KeyCapture::default())]);
+/// form.select(FormSelection::Active(0));
+/// form.input(KeyCode::F(10));
+/// assert_eq!(form.status()[0].value(), "F(10)");
+/// ```
+pub trait FieldWidget: CloneFieldWidget + Send + Sync {
+    /// Handles `key` while the field is active. Return [`FormEvent::Ignored`] to let the form
+    /// handle the key as usual, e.g. `Esc` to leave the field.
+    fn handle_input(&mut self, key: KeyCode) -> FormEvent;
+
+    /// Handles `key` pressed with `modifiers` while the field is active, see
+    /// [`Form::input_event`](crate::Form::input_event). Return [`FormEvent::Ignored`] to let the
+    /// form handle the chord as usual, which the default implementation does for every chord.
+    fn handle_chord(&mut self, modifiers: KeyModifiers, key: KeyCode) -> FormEvent {
+        _ = (modifiers, key);
+        FormEvent::Ignored
+    }
+
+    /// Renders the content of the field into `area`, inside its border. `active` is `true` while
+    /// the field is receiving input.
+    fn render(&self, area: Rect, buf: &mut Buffer, active: bool);
+
+    /// The current value of the field.
+    fn value(&self) -> String;
+
+    /// Replaces the content of the widget with `value`, given in the format returned by
+    /// [`value`](FieldWidget::value), when the form sets the field's value itself, e.g. when the
+    /// form is [reset](crate::Form::reset) or normalizes the value on submit. The field's value is
+    /// read back from the widget afterwards, so the default implementation, which does nothing,
+    /// leaves the field unchanged.
+    fn set_value(&mut self, value: &str) {
+        _ = value;
+    }
+
+    /// Number of rows the content of the field takes up. Defaults to 1.
+    fn height(&self) -> u16 {
+        1
+    }
+}
+
+/// Clones boxed [`FieldWidget`]s, so forms containing them can be cloned. Implemented for every
+/// `FieldWidget` that implements [`Clone`].
+#[doc(hidden)]
+pub trait CloneFieldWidget {
+    fn clone_box(&self) -> Box<dyn FieldWidget>;
+}
+
+impl<T: FieldWidget + Clone + 'static> CloneFieldWidget for T {
+    fn clone_box(&self) -> Box<dyn FieldWidget> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn FieldWidget> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+impl fmt::Debug for dyn FieldWidget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FieldWidget").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,40 @@
+//! Parsing human friendly durations, like `1h30m`, for duration fields.
+
+use std::time::Duration;
+
+/// Parses a duration made of numbers followed by a unit, `d`, `h`, `m`, `s` or `ms`, like `1h30m`
+/// or `1.5s`. Returns a message describing the problem if `text` isn't a duration.
+pub(crate) fn parse(text: &str) -> Result<Duration, String> {
+    let mut rest = text.trim();
+    if rest.is_empty() {
+        return Err("Enter a duration like 1h30m".to_string());
+    }
+
+    let mut secs = 0.0;
+    while !rest.is_empty() {
+        let end = rest
+            .find(|ch: char| !ch.is_ascii_digit() && ch != '.')
+            .unwrap_or(rest.len());
+        let (number, tail) = rest.split_at(end);
+        let Ok(number) = number.parse::<f64>() else {
+            return Err(format!("Expected a number at {rest:?}"));
+        };
+
+        let end = tail
+            .find(|ch: char| !ch.is_ascii_alphabetic())
+            .unwrap_or(tail.len());
+        let (unit, tail) = tail.split_at(end);
+        secs += number
+            * match unit {
+                "d" => 86400.0,
+                "h" => 3600.0,
+                "m" => 60.0,
+                "s" => 1.0,
+                "ms" => 0.001,
+                "" => return Err(format!("{number} is missing a unit, like s or m")),
+                unit => return Err(format!("Unknown unit {unit:?}")),
+            };
+        rest = tail.trim_start();
+    }
+    Duration::try_from_secs_f64(secs).map_err(|_| "Duration is too long".to_string())
+}
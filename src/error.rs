@@ -0,0 +1,31 @@
+use std::fmt;
+
+/// Errors returned by [`Form`](crate::Form) operations.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// No field matches the given index or name.
+    UnknownField,
+    /// The field's value didn't pass validation.
+    Invalid {
+        /// Name of the invalid field.
+        field: String,
+        /// Message describing why the field is invalid, if its validator provided one.
+        message: Option<String>,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnknownField => write!(f, "no such field"),
+            Error::Invalid {
+                field,
+                message: Some(message),
+            } => write!(f, "{field} is invalid: {message}"),
+            Error::Invalid { field, .. } => write!(f, "{field} is invalid"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
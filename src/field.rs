@@ -0,0 +1,807 @@
+use std::net::IpAddr;
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+
+use ratatui::prelude::{Alignment, Buffer, Rect};
+use ratatui::widgets::block::Position;
+
+use crate::duration;
+use crate::form::{bool_validator, FieldBuffer};
+use crate::value::ValueType;
+use crate::{
+    FieldKey, FieldState, FieldWidget, KeyValues, Normalize, StaticSuggestions, StringList,
+    SuggestionProvider, Tags,
+};
+
+#[derive(Clone, Debug)]
+pub(crate) enum FieldKind {
+    Text,
+    Select {
+        options: Vec<String>,
+        highlighted: usize,
+    },
+    TextArea {
+        rows: u16,
+    },
+    Number {
+        step: i64,
+        large_step: i64,
+        range: RangeInclusive<i64>,
+        /// Unit shown after the value, like `%`.
+        suffix: String,
+        /// Whether to show a bar filled according to where the value is in the range.
+        gauge: bool,
+    },
+    Ip,
+    Custom(Box<dyn FieldWidget>),
+}
+
+/// Controls how the options of an active select field are displayed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SelectDisplay {
+    /// Options float over the fields below the select field.
+    Popup,
+    /// Options are expanded inside the select field, pushing subsequent fields down.
+    Inline,
+}
+
+/// Definition of a single field, used to build a [`Form`](crate::Form).
+///
+/// # Example
+///
+/// ```
+/// # use tui_form_widget::{FieldDef, Form};
+/// let form = Form::from(vec![
+///     FieldDef::text("Name").value("Ferris"),
+///     FieldDef::select("Language", &["Rust", "C", "Zig"]),
+/// ]);
+/// assert_eq!(form.status()[0].value(), "Ferris");
+/// ```
+#[derive(Clone, Debug)]
+pub struct FieldDef<K = String>(pub(crate) FieldBuffer, pub(crate) K);
+
+impl FieldDef {
+    /// Keys the field by its name.
+    fn new(fb: FieldBuffer) -> Self {
+        let key = fb.name.clone();
+        Self(fb, key)
+    }
+
+    /// A free text field.
+    pub fn text(name: &str) -> Self {
+        Self::new(FieldBuffer::new(name, ""))
+    }
+
+    /// A field whose value is chosen from a list of `options`.
+    pub fn select(name: &str, options: &[&str]) -> Self {
+        let mut fb = FieldBuffer::new(name, "");
+        fb.kind = FieldKind::Select {
+            options: options.iter().map(|o| o.to_string()).collect(),
+            highlighted: 0,
+        };
+        Self::new(fb)
+    }
+
+    /// A multiline text field showing `rows` lines at a time. While it's active, `Enter` inserts a
+    /// newline and `Up`/`Down` move the cursor between lines, so leave it with `Esc`.
+    ///
+    /// The text is stored in a gap buffer, so editing in the middle of multi-kilobyte values stays
+    /// fast.
+    pub fn textarea(name: &str, rows: u16) -> Self {
+        let mut fb = FieldBuffer::new(name, "");
+        fb.kind = FieldKind::TextArea { rows: rows.max(1) };
+        Self::new(fb)
+    }
+
+    /// A field for a whole number. While it's active, `Up`/`Down` and `Ctrl+Up`/`Ctrl+Down` change
+    /// the number by its [step](Self::step), and `Shift+Up`/`Shift+Down` or `PageUp`/`PageDown` by
+    /// its large step. Only digits and `-` are allowed, and the field is invalid unless it's empty
+    /// or a number in its [range](Self::range).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crossterm::event::KeyCode;
+    /// # use tui_form_widget::{FieldDef, Form};
+    /// let mut form = Form::from(vec![FieldDef::number("Volume").value("7").step(1, 5).range(0..=10)]);
+    /// form.focus_first();
+    /// form.input(KeyCode::Up);
+    /// assert_eq!(form.status()[0].value(), "8");
+    /// form.input(KeyCode::PageUp);
+    /// assert_eq!(form.status()[0].value(), "10");
+    /// ```
+    pub fn number(name: &str) -> Self {
+        let mut fb = FieldBuffer::new(name, "");
+        fb.kind = FieldKind::Number {
+            step: 1,
+            large_step: 10,
+            range: i64::MIN..=i64::MAX,
+            suffix: String::new(),
+            gauge: false,
+        };
+        fb.value_type = ValueType::Int;
+        Self::new(fb).allowed_chars(|ch| ch.is_ascii_digit() || ch == '-')
+    }
+
+    /// A [number](Self::number) field for a percentage from 0 to 100, shown with a trailing `%`.
+    /// Use [`range`](Self::range) for other bounds and [`gauge`](Self::gauge) to show the value as
+    /// a bar too.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tui_form_widget::{test_utils, FieldDef, Form};
+    /// let form = Form::from(vec![FieldDef::percentage("Opacity").value("75").gauge()]);
+    /// let buf = test_utils::render(&form, 14, 3);
+    /// test_utils::assert_lines(
+    ///     &buf,
+    ///     &[
+    ///         "╭Opacity─────╮",
+    ///         "│75%   █████░│",
+    ///         "╰────────────╯",
+    ///     ],
+    /// );
+    /// ```
+    pub fn percentage(name: &str) -> Self {
+        Self::number(name).range(0..=100).suffix("%")
+    }
+
+    /// A field for a duration written like `1h30m`, as numbers followed by a unit: `d`, `h`, `m`,
+    /// `s` or `ms`. The field is invalid with a message describing the problem unless its value is
+    /// a duration, which is available parsed from [`Field::duration`](crate::Field::duration).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use tui_form_widget::{FieldDef, Form};
+    /// let mut form = Form::from(vec![
+    ///     FieldDef::duration("Timeout").value("1h30m"),
+    ///     FieldDef::duration("Retry").value("5x"),
+    /// ]);
+    /// let status = form.submit();
+    /// assert_eq!(status[0].duration(), Some(Duration::from_secs(5400)));
+    /// assert_eq!(status[1].error(), Some("Unknown unit \"x\""));
+    /// ```
+    pub fn duration(name: &str) -> Self {
+        let mut def =
+            Self::text(name).validator_with_message(|val| duration::parse(val).map(|_| ()));
+        def.0.value_type = ValueType::Duration;
+        def
+    }
+
+    /// A field for an email address, lowercased and without whitespace as it's typed, and showing
+    /// a placeholder while it's empty. The field is invalid unless its value looks like an email
+    /// address, with a single `@` between a local part and a domain containing a `.`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tui_form_widget::{FieldDef, Form};
+    /// let mut form = Form::from(vec![FieldDef::email("Email"), FieldDef::email("Backup")]);
+    /// form.focus_first();
+    /// form.type_str("Ferris@Example.com\nferris@localhost");
+    /// let status = form.submit();
+    /// assert_eq!(status[0].value(), "ferris@example.com");
+    /// assert!(status[0].is_valid());
+    /// assert_eq!(status[1].error(), Some("\"ferris@localhost\" is not an email address"));
+    /// ```
+    pub fn email(name: &str) -> Self {
+        Self::text(name)
+            .placeholder("name@example.com")
+            .transform(|s: &str| {
+                s.chars()
+                    .filter(|ch| !ch.is_whitespace())
+                    .flat_map(char::to_lowercase)
+                    .collect()
+            })
+            .validator_with_message(|val| match is_email(val) {
+                true => Ok(()),
+                false => Err(format!("{val:?} is not an email address")),
+            })
+    }
+
+    /// A field for an `http` or `https` URL, see [`url_with_schemes`](Self::url_with_schemes).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tui_form_widget::{FieldDef, Form};
+    /// let mut form = Form::from(vec![
+    ///     FieldDef::url("Homepage").value("example.com/docs").default_scheme("https"),
+    ///     FieldDef::url("Mirror").value("ftp://example.com"),
+    /// ]);
+    /// let status = form.submit();
+    /// assert_eq!(status[0].value(), "https://example.com/docs");
+    /// assert_eq!(status[1].error(), Some("URLs must start with http:// or https://"));
+    /// ```
+    pub fn url(name: &str) -> Self {
+        Self::url_with_schemes(name, &["http", "https"])
+    }
+
+    /// A field for a URL starting with one of `schemes`, like `https`, followed by `://` and a
+    /// host. The field is invalid with a message describing the problem unless its value is such a
+    /// URL. Set a [default scheme](Self::default_scheme) to accept URLs without one.
+    ///
+    /// With the `url` feature enabled, values are parsed with the `url` crate and the parsed URL
+    /// is available from [`Field::url`](crate::Field::url).
+    pub fn url_with_schemes(name: &str, schemes: &[&str]) -> Self {
+        let schemes = schemes.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let mut fb = FieldBuffer::new(name, "");
+        fb.url_schemes = Some(schemes);
+        Self::new(fb)
+    }
+
+    /// Set the scheme, like `https`, inserted when a [URL](Self::url) field without one is
+    /// submitted. Has no effect on other fields.
+    pub fn default_scheme(mut self, scheme: &str) -> Self {
+        if self.0.url_schemes.is_some() {
+            self.0.default_scheme = Some(scheme.to_string());
+        }
+        self
+    }
+
+    /// A field for an IPv4 or IPv6 address. Once the first dot of an IPv4 address is typed, the
+    /// dot after each following octet is inserted automatically once the octet can't take another
+    /// digit, and typing it anyway is ignored. IPv6 addresses are typed as is. The field is invalid
+    /// unless its value is an address, which is available parsed from
+    /// [`Field::ip`](crate::Field::ip).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+    /// # use tui_form_widget::{FieldDef, Form};
+    /// let mut form = Form::from(vec![FieldDef::ip("Gateway"), FieldDef::ip("DNS")]);
+    /// form.focus_first();
+    /// form.type_str("192.1680.1");
+    /// form.focus("DNS").unwrap();
+    /// form.type_str("2001:db8::1");
+    /// let status = form.submit();
+    /// assert_eq!(status[0].value(), "192.168.0.1");
+    /// assert_eq!(status[0].ip(), Some(IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1))));
+    /// assert_eq!(status[1].value(), "2001:db8::1");
+    /// assert_eq!(status[1].ip(), Some(IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1))));
+    /// ```
+    pub fn ip(name: &str) -> Self {
+        let mut fb = FieldBuffer::new(name, "");
+        fb.kind = FieldKind::Ip;
+        fb.value_type = ValueType::Ip;
+        Self::new(fb)
+            .allowed_chars(|ch| ch.is_ascii_hexdigit() || ch == '.' || ch == ':')
+            .validator_with_message(|val| match val.parse::<IpAddr>() {
+                Ok(_) => Ok(()),
+                Err(_) => Err(format!("{val:?} is not an IP address")),
+            })
+    }
+
+    /// A field editing a list of key/value pairs, like HTTP headers, with one row per pair. See
+    /// [`KeyValues`](crate::KeyValues) for the keys used to edit it, and
+    /// [`Field::pairs`](crate::Field::pairs) for the pairs entered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crossterm::event::KeyCode;
+    /// # use tui_form_widget::{FieldDef, Form, KeyValues};
+    /// let mut form = Form::from(vec![FieldDef::key_values(
+    ///     "Headers",
+    ///     KeyValues::new([("Accept", "text/html")]),
+    /// )]);
+    /// form.focus_first();
+    /// form.input(KeyCode::Insert);
+    /// form.type_str("User-Agent=curl");
+    /// assert_eq!(
+    ///     form.status()[0].pairs(),
+    ///     [
+    ///         ("Accept".to_string(), "text/html".to_string()),
+    ///         ("User-Agent".to_string(), "curl".to_string()),
+    ///     ]
+    /// );
+    /// ```
+    pub fn key_values(name: &str, pairs: KeyValues) -> Self {
+        let mut def = Self::custom(name, pairs);
+        def.0.value_type = ValueType::Pairs;
+        def
+    }
+
+    /// A field editing a list of strings, with one row per entry. See
+    /// [`StringList`](crate::StringList) for the keys used to edit it, and
+    /// [`Field::items`](crate::Field::items) for the entries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    /// # use tui_form_widget::{FieldDef, Form, StringList};
+    /// let mut form = Form::from(vec![FieldDef::string_list("Hosts", StringList::new(["alpha"]))]);
+    /// form.focus_first();
+    /// form.input(KeyCode::Insert);
+    /// form.type_str("beta");
+    /// form.input_event(KeyEvent::new(KeyCode::Up, KeyModifiers::ALT));
+    /// assert_eq!(form.status()[0].items(), ["beta", "alpha"]);
+    /// ```
+    pub fn string_list(name: &str, items: StringList) -> Self {
+        let mut def = Self::custom(name, items);
+        def.0.value_type = ValueType::List;
+        def
+    }
+
+    /// A field editing a set of tags, shown as chips. See [`Tags`](crate::Tags) for the keys used
+    /// to edit it, and [`Field::items`](crate::Field::items) for the tags.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tui_form_widget::{test_utils, FieldDef, Form, Tags};
+    /// let mut form = Form::from(vec![FieldDef::tags("Topics", Tags::new(["rust"]))]);
+    /// form.focus_first();
+    /// form.type_str("tui,forms,");
+    /// assert_eq!(form.status()[0].items(), ["rust", "tui", "forms"]);
+    ///
+    /// let buf = test_utils::render(&form, 24, 3);
+    /// assert_eq!(test_utils::lines(&buf)[1], "│[rust] [tui] [forms]  │");
+    /// ```
+    pub fn tags(name: &str, tags: Tags) -> Self {
+        let mut def = Self::custom(name, tags);
+        def.0.value_type = ValueType::List;
+        def
+    }
+
+    /// A field for a file system path, which can be picked from a file browser instead of typed.
+    ///
+    /// While the field is active, `Tab` opens the browser at the directory of the path typed, or
+    /// the current directory. `Up`/`Down` move through the directory's entries, typing filters
+    /// them by name, `Enter` or `Right` opens a directory and `Left` goes up to the parent. `Enter`
+    /// on a file, or on `./` for the directory itself, picks it as the field's value, and `Esc`
+    /// closes the browser without picking anything.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crossterm::event::KeyCode;
+    /// # use tui_form_widget::{FieldDef, Form};
+    /// let dir = std::env::temp_dir().join("tui-form-widget-path-doctest");
+    /// std::fs::create_dir_all(dir.join("config")).unwrap();
+    /// std::fs::write(dir.join("config").join("app.toml"), "").unwrap();
+    ///
+    /// let mut form = Form::from(vec![FieldDef::path("Config").value(&format!("{}/", dir.display()))]);
+    /// form.focus_first();
+    /// form.input(KeyCode::Tab);
+    /// form.type_str("conf");
+    /// form.input(KeyCode::Enter);
+    /// form.input(KeyCode::Down);
+    /// form.input(KeyCode::Down);
+    /// form.input(KeyCode::Enter);
+    /// assert_eq!(
+    ///     form.status()[0].value(),
+    ///     dir.join("config").join("app.toml").to_str().unwrap()
+    /// );
+    /// ```
+    pub fn path(name: &str) -> Self {
+        let mut def = Self::text(name);
+        def.0.browse = true;
+        def
+    }
+
+    /// A field of a custom kind implemented by `widget`, see [`FieldWidget`]. The field's value is
+    /// kept in sync with [`FieldWidget::value`].
+    pub fn custom(name: &str, widget: impl FieldWidget + 'static) -> Self {
+        let mut fb = FieldBuffer::new(name, &widget.value());
+        fb.kind = FieldKind::Custom(Box::new(widget));
+        Self::new(fb)
+    }
+}
+
+impl<K> FieldDef<K> {
+    /// Set the key the field is looked up by, in place of its name. Fields of a form must all have
+    /// keys of the same type; see [`FieldKey`] for an example.
+    pub fn key<L: FieldKey>(self, key: L) -> FieldDef<L> {
+        FieldDef(self.0, key)
+    }
+
+    /// Set how much the value of a [number](Self::number) field changes by per step, and per large
+    /// step. The default steps are 1 and 10. Has no effect on other fields.
+    pub fn step(mut self, step: i64, large_step: i64) -> Self {
+        if let FieldKind::Number {
+            step: s,
+            large_step: l,
+            ..
+        } = &mut self.0.kind
+        {
+            (*s, *l) = (step, large_step);
+        }
+        self
+    }
+
+    /// Show `suffix`, like a unit, after the value of a [number](Self::number) field. It's only
+    /// displayed, and isn't part of the field's value. Has no effect on other fields.
+    pub fn suffix(mut self, suffix: &str) -> Self {
+        if let FieldKind::Number { suffix: s, .. } = &mut self.0.kind {
+            *s = suffix.to_string();
+        }
+        self
+    }
+
+    /// Show a bar next to the value of a [number](Self::number) field, filled according to where
+    /// the value is in the field's [range](Self::range). Has no effect on other fields.
+    pub fn gauge(mut self) -> Self {
+        if let FieldKind::Number { gauge, .. } = &mut self.0.kind {
+            *gauge = true;
+        }
+        self
+    }
+
+    /// Set the range of values allowed in a [number](Self::number) field. Stepping the value stops
+    /// at the ends of the range. Has no effect on other fields.
+    pub fn range(mut self, range: RangeInclusive<i64>) -> Self {
+        if let FieldKind::Number { range: r, .. } = &mut self.0.kind {
+            *r = range;
+        }
+        self
+    }
+
+    /// Suggest completions from `provider` while the field is being edited.
+    pub fn suggestions(mut self, provider: impl SuggestionProvider + 'static) -> Self {
+        self.0.suggestions = Some(Arc::new(provider));
+        self
+    }
+
+    /// Suggest completions from a fixed list of `options`, fuzzy matched against the input. See
+    /// [`StaticSuggestions`].
+    pub fn suggestion_list(self, options: Vec<String>) -> Self {
+        self.suggestions(StaticSuggestions::new(options))
+    }
+
+    /// Validate the field with `validator` instead of the form's validator.
+    ///
+    /// The result is cached until the field's value changes, so `validator` can be expensive, e.g.
+    /// parsing the value, without running on every frame.
+    pub fn validator(mut self, validator: impl Fn(&str) -> bool + Send + Sync + 'static) -> Self {
+        self.0.validator = Some(bool_validator(validator));
+        self
+    }
+
+    /// Validate the field with `validator` instead of the form's validator, where `validator`
+    /// returns a message describing why the value is invalid. The message is shown under the field
+    /// while it's marked as invalid and is available from [`Field::error`](crate::Field::error).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tui_form_widget::{FieldDef, Form};
+    /// let mut form = Form::from(vec![FieldDef::text("Port").value("http").validator_with_message(
+    ///     |val| match val.parse::<u16>() {
+    ///         Ok(_) => Ok(()),
+    ///         Err(_) => Err(format!("{val:?} is not a port")),
+    ///     },
+    /// )]);
+    /// form.submit();
+    /// assert_eq!(form.status()[0].error(), Some("\"http\" is not a port"));
+    /// ```
+    pub fn validator_with_message(
+        mut self,
+        validator: impl Fn(&str) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        self.0.validator = Some(Arc::new(move |val| validator(val).map_err(Some)));
+        self
+    }
+
+    /// Only allow chars for which `allowed` returns `true` in the field. The field is invalid while
+    /// its value contains any other char, and those chars are highlighted where they are with the
+    /// form's [`invalid_char_style`](crate::Form::invalid_char_style), unless the field is
+    /// [secret](Self::secret).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::prelude::*;
+    /// # use tui_form_widget::{test_utils, FieldDef, Form};
+    /// let mut form = Form::from(vec![
+    ///     FieldDef::text("Phone").value("555-12a4").allowed_chars(|ch| ch.is_ascii_digit() || ch == '-'),
+    /// ]);
+    /// assert!(!form.submit()[0].is_valid());
+    ///
+    /// let buf = test_utils::render(&form, 12, 3);
+    /// test_utils::assert_style(&buf, 7, 1, Style::default().red().underlined());
+    /// ```
+    pub fn allowed_chars(mut self, allowed: impl Fn(char) -> bool + Send + Sync + 'static) -> Self {
+        self.0.allowed_chars = Some(Arc::new(allowed));
+        self
+    }
+
+    /// Warn about the field's value with the message returned by `warning`, without making the
+    /// field invalid, e.g. for a weak password. Warnings are reported by
+    /// [`Field::warning`](crate::Field::warning) and shown under the field like errors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tui_form_widget::{FieldDef, Form, Severity};
+    /// let mut form = Form::from(vec![FieldDef::text("Password").value("hunter2").warning(|val| {
+    ///     match val.len() {
+    ///         ..=11 => Err("Short passwords are easy to guess".to_string()),
+    ///         _ => Ok(()),
+    ///     }
+    /// })]);
+    /// let status = form.submit();
+    /// assert!(status[0].is_valid());
+    /// assert_eq!(status[0].severity(), Some(Severity::Warning));
+    /// ```
+    pub fn warning(
+        mut self,
+        warning: impl Fn(&str) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        self.0.warning = Some(Arc::new(move |val| warning(val).map_err(Some)));
+        self
+    }
+
+    /// Additionally validate the field against the value of the field named `other`, e.g. for
+    /// "confirm password" fields. `validator` is called with this field's value and the value of
+    /// `other`, so editing `other` updates this field's validity too.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tui_form_widget::{FieldDef, Form, FormSelection, ValidationMode};
+    /// let mut form = Form::from(vec![
+    ///     FieldDef::text("Password").value("hunter2"),
+    ///     FieldDef::text("Confirm").value("hunter2").linked_validator("Password", |c, p| c == p),
+    /// ]);
+    /// form.validation_mode(ValidationMode::Live);
+    /// assert!(form.status()[1].is_valid());
+    ///
+    /// form.select(FormSelection::Active(0));
+    /// form.append_selection('!');
+    /// assert!(!form.status()[1].is_valid());
+    /// ```
+    pub fn linked_validator(
+        mut self,
+        other: &str,
+        validator: impl Fn(&str, &str) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.0.link = Some((other.to_string(), Arc::new(validator)));
+        self
+    }
+
+    /// Remember up to `limit` submitted values of this field, which can be recalled with
+    /// `Up`/`Down` while the field is active and empty. See [`FormHistory`](crate::FormHistory).
+    pub fn history(mut self, limit: usize) -> Self {
+        self.0.history_limit = limit;
+        self
+    }
+
+    /// Mark the field as secret, e.g. for passwords. Its value is masked when rendered and never
+    /// recorded in history. Secret fields are also [sensitive](FieldDef::sensitive).
+    ///
+    /// With the `zeroize` feature enabled, the field's memory is zeroized when edited, on
+    /// [`Form::reset`](crate::Form::reset) and when the form is dropped. Values returned from
+    /// [`Form::status`](crate::Form::status) borrow from the form, but copying a value out of
+    /// [`Field::value`](crate::Field::value) makes a copy that's the caller's responsibility.
+    pub fn secret(mut self) -> Self {
+        self.0.secret = true;
+        self.0.val.secret = true;
+        self.0.sensitive = true;
+        self
+    }
+
+    /// Mark the field as sensitive. Its value is shown as `***` in `Debug` output and when a
+    /// [`Field`](crate::Field) is converted into a `String`, and is left out of
+    /// [`Form::to_public_map`](crate::Form::to_public_map).
+    pub fn sensitive(mut self) -> Self {
+        self.0.sensitive = true;
+        self
+    }
+
+    /// Rewrite text as it's typed into the field with `transform`, e.g. to uppercase a license
+    /// key or drop disallowed chars. `transform` is called with each inserted char and returns the
+    /// text to insert instead, which may be empty. Transforms are applied in the order they're
+    /// added, each to the output of the previous one.
+    ///
+    /// Unlike [`FieldDef::validator`], which only reports problems, transforms change the value
+    /// itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tui_form_widget::{FieldDef, Form, FormSelection};
+    /// let mut form = Form::from(vec![FieldDef::text("License key")
+    ///     .transform(|s: &str| s.to_uppercase())
+    ///     .transform(|s: &str| s.chars().filter(char::is_ascii_alphanumeric).collect())]);
+    /// form.select(FormSelection::Active(0));
+    /// for ch in "ab-12 c".chars() {
+    ///     form.append_selection(ch);
+    /// }
+    /// assert_eq!(form.status()[0].value(), "AB12C");
+    /// ```
+    pub fn transform(mut self, transform: impl Fn(&str) -> String + Send + Sync + 'static) -> Self {
+        self.0.transforms.push(Arc::new(transform));
+        self
+    }
+
+    /// Move on to the next field once `len` chars have been typed, e.g. for one-time codes or
+    /// date segments split over several fields. Typing text longer than `len` with
+    /// [`Form::type_str`](crate::Form::type_str) continues in the next field, so a whole code can
+    /// be pasted at once. The last field of the form is left active.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tui_form_widget::{FieldDef, Form, FormSelection};
+    /// let mut form = Form::from(vec![
+    ///     FieldDef::text("Month").auto_advance(2),
+    ///     FieldDef::text("Year").auto_advance(4),
+    /// ]);
+    /// form.focus_first();
+    /// form.type_str("122024");
+    /// assert_eq!(form.status()[0].value(), "12");
+    /// assert_eq!(form.status()[1].value(), "2024");
+    /// assert_eq!(form.selected(), &FormSelection::Active(1));
+    /// ```
+    pub fn auto_advance(mut self, len: usize) -> Self {
+        self.0.auto_advance = Some(len);
+        self
+    }
+
+    /// Set a longer description of the field, shown in a popup next to it when the user presses
+    /// `?` while it's hovered. Pressing `?` again shows the help overlay instead, so fields with a
+    /// tooltip don't hide the form's key bindings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tui_form_widget::{test_utils, FieldDef, Form, FormSelection};
+    /// let mut form = Form::from(vec![
+    ///     FieldDef::text("Port").tooltip("TCP port the server listens on"),
+    /// ]);
+    /// form.select(FormSelection::Hovered(0));
+    /// form.input(crossterm::event::KeyCode::Char('?'));
+    ///
+    /// let buf = test_utils::render(&form, 20, 10);
+    /// test_utils::assert_lines(
+    ///     &buf,
+    ///     &[
+    ///         "╭Port──────────────╮",
+    ///         "│                  │",
+    ///         "╰──────────────────╯",
+    ///         "╭──────────────────╮",
+    ///         "│TCP port the      │",
+    ///         "│server listens on │",
+    ///         "╰──────────────────╯",
+    ///         "                    ",
+    ///         "                    ",
+    ///         "                    ",
+    ///     ],
+    /// );
+    /// ```
+    pub fn tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.0.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Show `placeholder` dimmed in the field while it's empty, e.g. an example of the value
+    /// expected. It's only displayed, and isn't part of the field's value.
+    pub fn placeholder(mut self, placeholder: &str) -> Self {
+        self.0.placeholder = Some(placeholder.to_string());
+        self
+    }
+
+    /// Set where the field's label is aligned on its border, overriding the form's
+    /// [`title_alignment`](crate::Form::title_alignment).
+    pub fn title_alignment(mut self, alignment: Alignment) -> Self {
+        self.0.title_alignment = Some(alignment);
+        self
+    }
+
+    /// Set which border the field's label is drawn on, overriding the form's
+    /// [`title_position`](crate::Form::title_position).
+    pub fn title_position(mut self, position: Position) -> Self {
+        self.0.title_position = Some(position);
+        self
+    }
+
+    /// Render the field's content with `render` instead of the form, e.g. for a one-off visual like
+    /// a color swatch. The form still draws the field's border, label and problems, lays it out and
+    /// handles its input, and calls `render` with the field's value, its [`FieldState`] and the
+    /// area inside the border. The cursor and any open options aren't drawn, and the value of
+    /// [secret](Self::secret) fields is passed unmasked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::prelude::*;
+    /// # use tui_form_widget::{test_utils, FieldDef, FieldState, Form};
+    /// let mut form = Form::from(vec![FieldDef::number("Rating").value("3").render_with(
+    ///     |val, state, area, buf| {
+    ///         let stars = "★".repeat(val.parse().unwrap_or(0));
+    ///         let style = match state {
+    ///             FieldState::Active => Style::default().yellow(),
+    ///             _ => Style::default(),
+    ///         };
+    ///         buf.set_stringn(area.x, area.y, stars, area.width as usize, style);
+    ///     },
+    /// )]);
+    /// form.focus_first();
+    /// form.input(crossterm::event::KeyCode::Up);
+    /// let buf = test_utils::render(&form, 12, 3);
+    /// assert_eq!(test_utils::lines(&buf)[1], "│★★★★      │");
+    /// test_utils::assert_style(&buf, 1, 1, Style::default().yellow());
+    /// ```
+    pub fn render_with(
+        mut self,
+        render: impl Fn(&str, FieldState, Rect, &mut Buffer) + Send + Sync + 'static,
+    ) -> Self {
+        self.0.render = Some(Arc::new(render));
+        self
+    }
+
+    /// Attach metadata to the field, like an ID, unit or schema name the application needs when
+    /// handling it. Metadata isn't shown, and can be read back with [`Field::meta`] and
+    /// [`SubmittedField::meta`].
+    ///
+    /// [`Field::meta`]: crate::Field::meta
+    /// [`SubmittedField::meta`]: crate::SubmittedField::meta
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tui_form_widget::{FieldDef, Form};
+    /// let form = Form::from(vec![FieldDef::text("Weight").meta("id", "w1").meta("unit", "kg")]);
+    /// let status = form.status();
+    /// assert_eq!(status[0].meta("unit"), Some("kg"));
+    /// assert_eq!(status[0].meta("min"), None);
+    /// ```
+    pub fn meta(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.0.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    /// Set how the field's value is cleaned up when the form is submitted, instead of the form's
+    /// [`Form::normalize`](crate::Form::normalize) setting. The values of
+    /// [textarea](Self::textarea) and [custom](Self::custom) fields, like a
+    /// [string list](Self::string_list), are cleaned up line by line, so they keep their lines.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tui_form_widget::{FieldDef, Form, Normalize, StringList};
+    /// let hosts = StringList::new([" alpha  one", "beta "]);
+    /// let mut form = Form::from(vec![
+    ///     FieldDef::string_list("Hosts", hosts.clone()),
+    ///     FieldDef::string_list("Mirrors", hosts).normalize(Normalize::TRIM.collapse_whitespace()),
+    /// ]);
+    /// form.normalize(Normalize::TRIM.collapse_whitespace());
+    /// let status = form.submit();
+    /// assert_eq!(status[0].items(), [" alpha  one", "beta "]);
+    /// assert_eq!(status[1].items(), ["alpha one", "beta"]);
+    ///
+    /// let mut form = Form::from(vec![FieldDef::textarea("Notes", 2)
+    ///     .value(" first  line \nsecond ")
+    ///     .normalize(Normalize::TRIM.collapse_whitespace())]);
+    /// assert_eq!(form.submit()[0].value(), "first line\nsecond");
+    /// ```
+    pub fn normalize(mut self, normalize: Normalize) -> Self {
+        self.0.normalize = Some(normalize);
+        self
+    }
+
+    /// Set the initial value of the field.
+    pub fn value(mut self, val: &str) -> Self {
+        self.0.set_value(val.to_string());
+        self
+    }
+}
+
+/// Returns whether `val` looks like an email address: a local part and a domain of dot separated
+/// labels, separated by a single `@`, without whitespace.
+fn is_email(val: &str) -> bool {
+    let Some((local, domain)) = val.split_once('@') else {
+        return false;
+    };
+    let labels = domain.split('.').collect::<Vec<_>>();
+    !local.is_empty()
+        && !val.contains(char::is_whitespace)
+        && !domain.contains('@')
+        && labels.len() > 1
+        && labels.iter().all(|label| !label.is_empty())
+}
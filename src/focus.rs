@@ -0,0 +1,37 @@
+//! Integrating forms with application-wide focus management.
+
+use crate::{FieldKey, Form};
+
+/// A widget that can have the keyboard focus, for applications moving focus between several
+/// panels.
+///
+/// A [`Form`] without focus ignores [`input`](Form::input) and is rendered muted, with its
+/// selection and cursor hidden, until it's focused again. Forms have focus by default.
+///
+/// # Example
+///
+/// ```
+/// # use crossterm::event::KeyCode;
+/// # use tui_form_widget::{Focusable, Form, FormEvent};
+/// let mut form = Form::from(vec!["Name"]);
+/// form.set_focus(false);
+/// assert_eq!(form.input(KeyCode::Enter), FormEvent::Ignored);
+/// ```
+pub trait Focusable {
+    /// Returns `true` if the widget has the focus.
+    fn has_focus(&self) -> bool;
+
+    /// Give the focus to the widget, or take it away.
+    fn set_focus(&mut self, focus: bool);
+}
+
+impl<K: FieldKey> Focusable for Form<K> {
+    fn has_focus(&self) -> bool {
+        self.focused
+    }
+
+    fn set_focus(&mut self, focus: bool) {
+        self.changed();
+        self.focused = focus;
+    }
+}
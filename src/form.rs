@@ -1,50 +1,142 @@
-use crossterm::event::KeyCode;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::net::IpAddr;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::widgets::block::Position;
 use ratatui::{prelude::*, widgets::*};
 
-use crate::widget::Renderer;
+use crate::browser::{Browser, BrowserEvent};
+use crate::custom::FieldWidget;
+use crate::duration;
+use crate::error::Error;
+use crate::field::{FieldDef, FieldKind, SelectDisplay};
+use crate::history::{self, FormHistory};
+use crate::key::FieldKey;
+use crate::keymap::{Action, KeyMap};
+use crate::label::LabelProvider;
+use crate::normalize::Normalize;
+use crate::record::RecordedInput;
+use crate::secret::SecretString;
+use crate::suggest::SuggestionProvider;
+use crate::text::TextBuffer;
+use crate::url;
+use crate::value::{pairs, FieldValue, ValueType};
+use crate::widget::{FieldState, PopupRenderer, RenderCache, Renderer};
 
 pub enum FieldStatus {
     Valid,
     Invalid,
 }
 
-impl Into<String> for Field<'_> {
-    fn into(self) -> String {
-        self.fd.val.to_string()
+/// Placeholder shown instead of the value of sensitive fields.
+pub(crate) const REDACTED: &str = "***";
+
+/// Converts into a copy of the field's [redacted value](Field::redacted_value), so sensitive
+/// values aren't leaked by accident. Use [`Field::value`] for the value itself. Converting into a
+/// [`Cow`] borrows the redacted value instead of copying it.
+///
+/// # Example
+///
+/// ```
+/// # use std::borrow::Cow;
+/// # use tui_form_widget::{FieldDef, Form};
+/// let form = Form::from(vec![
+///     FieldDef::text("User").value("ferris"),
+///     FieldDef::text("Password").value("hunter2").secret(),
+/// ]);
+/// let values: Vec<String> = form.status().into_iter().map(String::from).collect();
+/// assert_eq!(values, ["ferris", "***"]);
+/// let values: Vec<Cow<str>> = form.status().into_iter().map(Cow::from).collect();
+/// assert_eq!(values, ["ferris", "***"]);
+/// assert_eq!(form.status()[1].value(), "hunter2");
+/// ```
+impl From<Field<'_>> for String {
+    fn from(field: Field<'_>) -> Self {
+        field.redacted_value().to_string()
+    }
+}
+
+/// Borrows the field's [redacted value](Field::redacted_value) from the form, like converting
+/// into a [`String`] without the copy.
+impl<'a> From<Field<'a>> for Cow<'a, str> {
+    fn from(field: Field<'a>) -> Self {
+        Cow::Borrowed(field.redacted_value())
     }
 }
 
 /// A reference to a specific field's data in a form that also indicates whether or not it's valid.
+///
+/// Everything is borrowed from the form, including the strings returned, which can outlive the
+/// `Field` itself. Use [`Field::into_owned`] for a copy that doesn't borrow from the form.
+///
+/// # Example
+///
+/// ```
+/// # use tui_form_widget::Form;
+/// let form = Form::from(vec![("Name", "Ferris"), ("Email", "ferris@example.com")]);
+/// let values: Vec<&str> = form.iter_fields().map(|field| field.value()).collect();
+/// assert_eq!(values, ["Ferris", "ferris@example.com"]);
+/// ```
 pub struct Field<'a> {
     fd: FieldData<'a>,
     status: FieldStatus,
+    error: Option<&'a str>,
+    warning: Option<&'a str>,
 }
 
 impl<'a> Field<'a> {
-    pub(crate) fn valid(name: &'a str, val: &'a str) -> Field<'a> {
+    pub(crate) fn valid(fb: &'a FieldBuffer) -> Field<'a> {
         Self {
-            fd: FieldData { name, val },
+            fd: FieldData::from(fb),
             status: FieldStatus::Valid,
+            error: None,
+            warning: None,
         }
     }
 
-    pub(crate) fn invalid(name: &'a str, val: &'a str) -> Field<'a> {
+    pub(crate) fn invalid(fb: &'a FieldBuffer, error: Option<&'a str>) -> Field<'a> {
         Self {
-            fd: FieldData { name, val },
+            fd: FieldData::from(fb),
             status: FieldStatus::Invalid,
+            error,
+            warning: None,
         }
     }
 
     /// Name of the underlying field.
-    pub fn name(&self) -> &str {
+    pub fn name(&self) -> &'a str {
         self.fd.name
     }
 
     /// Value of the underlying field.
-    pub fn value(&self) -> &str {
+    pub fn value(&self) -> &'a str {
         self.fd.val
     }
 
+    /// Value of the underlying field, or `***` if the field is sensitive. This is also what's shown
+    /// in the field's `Debug` output.
+    pub fn redacted_value(&self) -> &'a str {
+        if self.fd.sensitive {
+            REDACTED
+        } else {
+            self.fd.val
+        }
+    }
+
+    /// Returns `true` if the underlying field is marked as sensitive.
+    pub fn is_sensitive(&self) -> bool {
+        self.fd.sensitive
+    }
+
+    /// Returns `true` if the underlying field has been edited, or activated and then left.
+    pub fn is_touched(&self) -> bool {
+        self.fd.touched
+    }
+
     /// Returns `true` if the underlying field is currently valid.
     pub fn is_valid(&self) -> bool {
         match self.status {
@@ -52,17 +144,321 @@ impl<'a> Field<'a> {
             FieldStatus::Invalid => false,
         }
     }
+
+    /// Message describing why the underlying field is invalid, if it is and its validator provided
+    /// one. See [`FieldDef::validator_with_message`](crate::FieldDef::validator_with_message).
+    pub fn error(&self) -> Option<&'a str> {
+        self.error
+    }
+
+    /// Warning about the underlying field's value, if it has one. Warnings don't make the field
+    /// invalid. See [`FieldDef::warning`](crate::FieldDef::warning).
+    pub fn warning(&self) -> Option<&'a str> {
+        self.warning
+    }
+
+    /// Value of the underlying field parsed as a [duration](crate::FieldDef::duration), or `None`
+    /// if it isn't one.
+    pub fn duration(&self) -> Option<Duration> {
+        duration::parse(self.fd.val).ok()
+    }
+
+    /// Value of the underlying field parsed as an [IP address](crate::FieldDef::ip), or `None` if
+    /// it isn't one.
+    pub fn ip(&self) -> Option<IpAddr> {
+        self.fd.val.parse().ok()
+    }
+
+    /// Value of the underlying field parsed as a [URL](crate::FieldDef::url), or `None` if it
+    /// isn't one. A default scheme is only part of the value once the form is submitted.
+    #[cfg(feature = "url")]
+    pub fn url(&self) -> Option<::url::Url> {
+        ::url::Url::parse(self.fd.val).ok()
+    }
+
+    /// Value of the underlying field split into [key/value pairs](crate::FieldDef::key_values), one
+    /// pair per `key=value` line. Lines without a `=` are skipped.
+    pub fn pairs(&self) -> Vec<(String, String)> {
+        pairs(self.fd.val)
+    }
+
+    /// Value of the underlying field split into the entries of a
+    /// [string list](crate::FieldDef::string_list), one per line.
+    pub fn items(&self) -> Vec<String> {
+        self.fd.val.lines().map(str::to_string).collect()
+    }
+
+    /// Value of the underlying field typed according to its kind, e.g. an
+    /// [`Int`](FieldValue::Int) for a [number](crate::FieldDef::number) field. See [`FieldValue`].
+    pub fn typed_value(&self) -> FieldValue {
+        FieldValue::parse(self.fd.value_type, self.fd.val)
+    }
+
+    /// Value of the underlying field's metadata for `key`, see
+    /// [`FieldDef::meta`](crate::FieldDef::meta).
+    pub fn meta(&self, key: &str) -> Option<&'a str> {
+        self.fd.metadata.get(key).map(String::as_str)
+    }
+
+    /// All of the underlying field's metadata.
+    pub fn metadata(&self) -> &'a BTreeMap<String, String> {
+        self.fd.metadata
+    }
+
+    /// Copies the underlying field's name, value, validity and metadata, so they can outlive the
+    /// form. Same as converting into a [`SubmittedField`].
+    pub fn into_owned(self) -> SubmittedField {
+        self.into()
+    }
+
+    /// Severity of the most severe problem with the underlying field, or `None` if it's fine.
+    pub fn severity(&self) -> Option<Severity> {
+        match (self.is_valid(), self.warning) {
+            (false, _) => Some(Severity::Error),
+            (true, Some(_)) => Some(Severity::Warning),
+            (true, None) => None,
+        }
+    }
+}
+
+impl fmt::Debug for Field<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Field")
+            .field("name", &self.name())
+            .field("value", &self.redacted_value())
+            .field("valid", &self.is_valid())
+            .field("error", &self.error)
+            .field("warning", &self.warning)
+            .finish()
+    }
+}
+
+/// How severe a problem with a field's value is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// The value is suspicious, but doesn't stop the form from being submitted.
+    Warning,
+    /// The value is invalid.
+    Error,
+}
+
+/// An owned copy of a field's name, value and validity, e.g. from [`Form::into_values`]. Unlike
+/// [`Field`] it doesn't borrow from the form, so it can outlive it or be sent to another thread.
+///
+/// Submitted fields carry the [key](FieldKey) of the field they were copied from.
+///
+/// With the `zeroize` feature enabled, the value of sensitive fields is zeroized when dropped.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SubmittedField<K = String> {
+    key: K,
+    name: String,
+    value: String,
+    sensitive: bool,
+    valid: bool,
+    error: Option<String>,
+    warning: Option<String>,
+    metadata: BTreeMap<String, String>,
+    value_type: ValueType,
+}
+
+impl<K> SubmittedField<K> {
+    /// Key of the field, see [`FieldKey`].
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Name of the field.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Value of the field.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Value of the field, or `***` if the field is sensitive. This is also what's shown in the
+    /// `Debug` output.
+    pub fn redacted_value(&self) -> &str {
+        if self.sensitive {
+            REDACTED
+        } else {
+            &self.value
+        }
+    }
+
+    /// Returns `true` if the field is marked as sensitive.
+    pub fn is_sensitive(&self) -> bool {
+        self.sensitive
+    }
+
+    /// Returns `true` if the field was valid.
+    pub fn is_valid(&self) -> bool {
+        self.valid
+    }
+
+    /// Message describing why the field was invalid, see [`Field::error`].
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Warning about the field's value, see [`Field::warning`].
+    pub fn warning(&self) -> Option<&str> {
+        self.warning.as_deref()
+    }
+
+    /// Value of the field parsed as a [duration](FieldDef::duration), or `None` if it isn't one.
+    pub fn duration(&self) -> Option<Duration> {
+        duration::parse(&self.value).ok()
+    }
+
+    /// Value of the field parsed as an [IP address](FieldDef::ip), or `None` if it isn't one.
+    pub fn ip(&self) -> Option<IpAddr> {
+        self.value.parse().ok()
+    }
+
+    /// Value of the field parsed as a [URL](FieldDef::url), or `None` if it isn't one.
+    #[cfg(feature = "url")]
+    pub fn url(&self) -> Option<::url::Url> {
+        ::url::Url::parse(&self.value).ok()
+    }
+
+    /// Value of the field split into [key/value pairs](FieldDef::key_values), see
+    /// [`Field::pairs`].
+    pub fn pairs(&self) -> Vec<(String, String)> {
+        pairs(&self.value)
+    }
+
+    /// Value of the field split into the entries of a [string list](FieldDef::string_list), one
+    /// per line.
+    pub fn items(&self) -> Vec<String> {
+        self.value.lines().map(str::to_string).collect()
+    }
+
+    /// Value of the field typed according to its kind, see [`Field::typed_value`].
+    pub fn typed_value(&self) -> FieldValue {
+        FieldValue::parse(self.value_type, &self.value)
+    }
+
+    /// Value of the field's metadata for `key`, see [`FieldDef::meta`].
+    pub fn meta(&self, key: &str) -> Option<&str> {
+        self.metadata.get(key).map(String::as_str)
+    }
+
+    /// All of the field's metadata.
+    pub fn metadata(&self) -> &BTreeMap<String, String> {
+        &self.metadata
+    }
+
+    /// Takes the value out of the field. Note this exposes the value of sensitive fields too.
+    pub fn into_value(mut self) -> String {
+        std::mem::take(&mut self.value)
+    }
+}
+
+/// Keys the copy by the field's name.
+impl From<Field<'_>> for SubmittedField {
+    fn from(field: Field<'_>) -> Self {
+        Self {
+            key: field.name().to_string(),
+            name: field.name().to_string(),
+            value: field.value().to_string(),
+            sensitive: field.is_sensitive(),
+            valid: field.is_valid(),
+            error: field.error().map(str::to_string),
+            warning: field.warning().map(str::to_string),
+            metadata: field.fd.metadata.clone(),
+            value_type: field.fd.value_type,
+        }
+    }
+}
+
+impl<K> fmt::Debug for SubmittedField<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SubmittedField")
+            .field("name", &self.name)
+            .field("value", &self.redacted_value())
+            .field("valid", &self.valid)
+            .field("error", &self.error)
+            .field("warning", &self.warning)
+            .field("metadata", &self.metadata)
+            .finish()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<K> Drop for SubmittedField<K> {
+    fn drop(&mut self) {
+        if self.sensitive {
+            crate::secret::clear(&mut self.value);
+        }
+    }
 }
 
 #[derive(Clone)]
 struct FieldData<'a> {
     name: &'a str,
     val: &'a str,
+    sensitive: bool,
+    touched: bool,
+    metadata: &'a BTreeMap<String, String>,
+    value_type: ValueType,
+}
+
+impl<'a> From<&'a FieldBuffer> for FieldData<'a> {
+    fn from(fb: &'a FieldBuffer) -> Self {
+        Self {
+            name: &fb.name,
+            val: fb.val.as_str(),
+            sensitive: fb.sensitive,
+            touched: fb.touched,
+            metadata: &fb.metadata,
+            value_type: fb.value_type,
+        }
+    }
 }
 
 type FormFieldStatus<'a> = Vec<Field<'a>>;
+
+/// Wraps a validator that only reports whether the value is valid.
+pub(crate) fn bool_validator(
+    validator: impl Fn(&str) -> bool + Send + Sync + 'static,
+) -> Validator {
+    Arc::new(move |val| if validator(val) { Ok(()) } else { Err(None) })
+}
+
+/// Refers to a field of a [`Form`], by index, by name or by [key](FieldKey). Methods taking a
+/// field accept either a `usize`, a `&str` or a key of the form's key type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FieldRef<'a, K = String> {
+    /// The field at this index.
+    Index(usize),
+    /// The first field with this name.
+    Name(&'a str),
+    /// The first field with this key.
+    Key(K),
+}
+
+impl<K> From<usize> for FieldRef<'_, K> {
+    fn from(i: usize) -> Self {
+        FieldRef::Index(i)
+    }
+}
+
+impl<'a, K> From<&'a str> for FieldRef<'a, K> {
+    fn from(name: &'a str) -> Self {
+        FieldRef::Name(name)
+    }
+}
+
+impl<K: FieldKey> From<K> for FieldRef<'_, K> {
+    fn from(key: K) -> Self {
+        FieldRef::Key(key)
+    }
+}
+
 /// Enumerates possible states of a [`Form`]s currently selected field.
-#[derive(PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum FormSelection {
     /// No field selected
     NoSelection,
@@ -72,53 +468,265 @@ pub enum FormSelection {
     Active(usize),
 }
 
+/// Controls what happens when navigating past the first or last field of a [`Form`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WrapBehavior {
+    /// Wrap around to the other end of the form.
+    Wrap,
+    /// Stay on the first/last field.
+    Stop,
+}
+
+/// Controls how [`Form::input`] handles `Esc`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EscBehavior {
+    /// Demote an active field to hovered, and a hovered field to no selection.
+    Demote,
+    /// Clear the active field's value. Demotes the field if it's already empty.
+    ClearField,
+    /// Demote an active field to hovered. Pressing `Esc` twice in a row deselects entirely.
+    DoubleEscDeselect,
+    /// Revert the active field to its value before it was activated, then demote it.
+    Revert,
+}
+
+/// Controls what's selected when a [`Form`] is opened or [reset](Form::reset), see
+/// [`Form::initial_focus`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum InitialFocus {
+    /// Nothing is selected.
+    NoSelection,
+    /// The first field is hovered.
+    Hovered,
+    /// The first field is active, ready for typing.
+    Active,
+}
+
+/// Controls when fields are validated.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ValidationMode {
+    /// Fields are only validated once the form has been submitted.
+    OnSubmit,
+    /// Fields are validated continuously as they're edited. Before the form is submitted, only
+    /// [touched](Field::is_touched) fields are rendered as invalid.
+    Live,
+}
+
+/// Outcome of handling input with [`Form::input`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FormEvent {
+    /// Input was handled by the form.
+    Handled,
+    /// Input had no effect on the form.
+    Ignored,
+    /// Navigation stopped at the first field (only with [`WrapBehavior::Stop`]).
+    ReachedStart,
+    /// Navigation stopped at the last field (only with [`WrapBehavior::Stop`]).
+    ReachedEnd,
+    /// The form was submitted from its last field and every field is valid (only with
+    /// [`Form::submit_on_enter`]).
+    Submitted,
+    /// The form was submitted from its last field but some fields are invalid (only with
+    /// [`Form::submit_on_enter`]).
+    SubmitFailed,
+}
+
+/// Outcome of validating a field, with a message describing why it's invalid if there is one.
+pub(crate) type Validation = Result<(), Option<String>>;
+pub(crate) type Validator = Arc<dyn Fn(&str) -> Validation + Send + Sync>;
+pub(crate) type Transform = Arc<dyn Fn(&str) -> String + Send + Sync>;
+pub(crate) type CharFilter = Arc<dyn Fn(char) -> bool + Send + Sync>;
+pub(crate) type RenderFn = Arc<dyn Fn(&str, FieldState, Rect, &mut Buffer) + Send + Sync>;
+pub(crate) type LinkedValidator = Arc<dyn Fn(&str, &str) -> bool + Send + Sync>;
+type FocusCallback = Arc<dyn Fn(&FormSelection, &FormSelection) + Send + Sync>;
+type LayoutCallback = Arc<dyn Fn(&[(usize, Rect)]) + Send + Sync>;
+
+#[derive(Clone)]
 pub(crate) struct FieldBuffer {
-    name: String,
-    val: String,
+    pub(crate) name: String,
+    pub(crate) val: TextBuffer,
+    pub(crate) kind: FieldKind,
+    pub(crate) suggestions: Option<Arc<dyn SuggestionProvider>>,
+    pub(crate) history: Vec<String>,
+    pub(crate) history_limit: usize,
+    pub(crate) secret: bool,
+    pub(crate) sensitive: bool,
+    pub(crate) validator: Option<Validator>,
+    pub(crate) warning: Option<Validator>,
+    pub(crate) allowed_chars: Option<CharFilter>,
+    pub(crate) normalize: Option<Normalize>,
+    pub(crate) transforms: Vec<Transform>,
+    /// Length at which typing moves on to the next field.
+    pub(crate) auto_advance: Option<usize>,
+    pub(crate) link: Option<(String, LinkedValidator)>,
+    pub(crate) tooltip: Option<String>,
+    pub(crate) placeholder: Option<String>,
+    pub(crate) title_alignment: Option<Alignment>,
+    pub(crate) title_position: Option<Position>,
+    /// Renders the field's content instead of the form, see [`FieldDef::render_with`].
+    pub(crate) render: Option<RenderFn>,
+    /// Whether the field can be filled in by browsing files.
+    pub(crate) browse: bool,
+    /// Schemes allowed in URL fields, `None` for other fields.
+    pub(crate) url_schemes: Option<Vec<String>>,
+    pub(crate) default_scheme: Option<String>,
+    pub(crate) metadata: BTreeMap<String, String>,
+    /// Type the value is parsed as by [`Field::typed_value`].
+    pub(crate) value_type: ValueType,
+    /// Result of the last validation, cleared whenever the value or a linked value changes.
+    pub(crate) validity: OnceLock<Validation>,
+    /// Result of the last warning check, cleared whenever the value changes.
+    pub(crate) warned: OnceLock<Option<String>>,
+    /// Whether the field was validated on its own, so its validity is shown before submitting.
+    pub(crate) checked: bool,
+    /// Whether the field has been edited, or activated and then left.
+    pub(crate) touched: bool,
+}
+
+impl FieldBuffer {
+    pub(crate) fn new(name: &str, val: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            val: TextBuffer::new(val),
+            kind: FieldKind::Text,
+            suggestions: None,
+            history: Vec::new(),
+            history_limit: 0,
+            secret: false,
+            sensitive: false,
+            validator: None,
+            warning: None,
+            allowed_chars: None,
+            normalize: None,
+            transforms: Vec::new(),
+            auto_advance: None,
+            link: None,
+            tooltip: None,
+            placeholder: None,
+            title_alignment: None,
+            title_position: None,
+            render: None,
+            browse: false,
+            url_schemes: None,
+            default_scheme: None,
+            metadata: BTreeMap::new(),
+            value_type: ValueType::Text,
+            validity: OnceLock::new(),
+            warned: OnceLock::new(),
+            checked: false,
+            touched: false,
+        }
+    }
+
+    /// Replaces the value of the field. The widget of a custom field is given the value, which is
+    /// then read back from it, see [`FieldWidget::set_value`].
+    pub(crate) fn set_value(&mut self, val: String) {
+        match &mut self.kind {
+            FieldKind::Custom(widget) => {
+                widget.set_value(&val);
+                self.val.set(widget.value());
+            }
+            _ => self.val.set(val),
+        }
+    }
+
+    /// Value of the field, or `***` if the field is sensitive.
+    fn redacted_value(&self) -> &str {
+        if self.sensitive {
+            REDACTED
+        } else {
+            self.val.as_str()
+        }
+    }
+}
+
+impl fmt::Debug for FieldBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let history: &dyn fmt::Debug = if self.sensitive {
+            &REDACTED
+        } else {
+            &self.history
+        };
+        f.debug_struct("FieldBuffer")
+            .field("name", &self.name)
+            .field("value", &self.redacted_value())
+            .field("kind", &self.kind)
+            .field("history", history)
+            .field("secret", &self.secret)
+            .field("sensitive", &self.sensitive)
+            .finish_non_exhaustive()
+    }
 }
 
 impl From<Vec<(&str, &str)>> for Form {
     fn from(value: Vec<(&str, &str)>) -> Self {
-        Self {
-            fields: value
+        Self::from(
+            value
                 .into_iter()
-                .map(|(d_name, d_val)| FieldBuffer {
-                    name: d_name.to_string(),
-                    val: d_val.to_string(),
-                })
-                .collect(),
-            ..Default::default()
-        }
+                .map(|(d_name, d_val)| FieldBuffer::new(d_name, d_val))
+                .collect::<Vec<_>>(),
+        )
     }
 }
 
 impl From<Vec<&str>> for Form {
     fn from(value: Vec<&str>) -> Self {
-        Self {
-            fields: value
+        Self::from(
+            value
                 .into_iter()
-                .map(|d_name| FieldBuffer {
-                    name: d_name.to_string(),
-                    val: String::new(),
-                })
-                .collect(),
-            ..Default::default()
-        }
+                .map(|d_name| FieldBuffer::new(d_name, ""))
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+impl<K: FieldKey> From<Vec<FieldDef<K>>> for Form<K> {
+    fn from(value: Vec<FieldDef<K>>) -> Self {
+        let (fields, keys) = value.into_iter().map(|def| (def.0, def.1)).unzip();
+        Self::with_fields(fields, keys)
+    }
+}
+
+/// Builds a form with a field per entry, named by the key and prefilled with the value. Since
+/// `HashMap` isn't ordered, fields are sorted by name; use a `Vec` of pairs to choose the order.
+impl From<HashMap<String, String>> for Form {
+    fn from(value: HashMap<String, String>) -> Self {
+        Self::from(value.into_iter().collect::<BTreeMap<_, _>>())
+    }
+}
+
+/// Builds a form with a field per entry, in key order, named by the key and prefilled with the
+/// value.
+impl From<BTreeMap<String, String>> for Form {
+    fn from(value: BTreeMap<String, String>) -> Self {
+        Self::from(
+            value
+                .iter()
+                .map(|(name, val)| FieldBuffer::new(name, val))
+                .collect::<Vec<_>>(),
+        )
     }
 }
 
+/// Keys each field by its name.
 impl From<Vec<FieldBuffer>> for Form {
     fn from(value: Vec<FieldBuffer>) -> Self {
-        Self {
-            fields: value,
-            ..Default::default()
-        }
+        let keys = value.iter().map(|fb| fb.name.clone()).collect();
+        Self::with_fields(value, keys)
     }
 }
 
 /// A widget to display data in a collection of fields, and allow editing of a currently selected
 /// field.
 ///
+/// Cloning a form copies its values and state, while validators and suggestion providers are
+/// shared between the clones. Forms are `Send` and `Sync`, so they can be held in state shared
+/// across threads.
+///
+/// Fields are identified by index or by name, and by a [key](FieldKey) of type `K`. Keys default
+/// to the field names; key fields with an enum of the application's own for lookups that are
+/// checked at compile time.
+///
 /// # Example
 ///
 /// ```
@@ -136,28 +744,139 @@ impl From<Vec<FieldBuffer>> for Form {
 /// form.append_selection('a');
 /// assert!(form.status()[0].is_valid());
 /// ```
-pub struct Form {
+#[derive(Clone)]
+pub struct Form<K = String> {
     selected: FormSelection,
     pub(crate) fields: Vec<FieldBuffer>,
+    /// Key of each field, see [`FieldKey`].
+    keys: Vec<K>,
     pub(crate) submitted: bool,
-    validation_fn: Box<dyn Fn(&str) -> bool + 'static>,
+    validation_fn: Validator,
+    validation_mode: ValidationMode,
+    normalize: Normalize,
+    wrap: WrapBehavior,
+    esc: EscBehavior,
+    initial_focus: InitialFocus,
+    submit_on_enter: bool,
+    last_action_escape: bool,
+    value_before_active: Option<SecretString>,
+    pub(crate) keymap: KeyMap,
+    pub(crate) show_help: bool,
+    pub(crate) show_tooltip: bool,
+    pub(crate) show_hints: bool,
+    pub(crate) sanitize: bool,
+    pub(crate) focused: bool,
+    pub(crate) select_display: SelectDisplay,
+    pub(crate) title_alignment: Alignment,
+    pub(crate) title_position: Position,
+    /// Type of border drawn around popups and the help overlay.
+    pub(crate) border_type: BorderType,
+    /// Type of border and borders drawn around fields, indexed by [`FieldState`].
+    pub(crate) field_borders: [(BorderType, Borders); 5],
+    /// Space between the borders of fields and their content.
+    pub(crate) padding: Padding,
+    pub(crate) suggestions: Vec<String>,
+    /// File browser open for the active field.
+    pub(crate) browser: Option<Browser>,
+    pub(crate) composition: String,
+    /// Whether the whole value of the active field is selected, see [`Action::SelectAll`].
+    pub(crate) all_selected: bool,
+    pub(crate) suggestion: Option<usize>,
+    history_index: Option<usize>,
+    pub(crate) hints: Vec<(String, String)>,
+    pub(crate) recording: Option<Vec<RecordedInput>>,
+    on_focus_change: Option<FocusCallback>,
+    pub(crate) on_layout_change: Option<LayoutCallback>,
+    pub(crate) labels: Option<Arc<dyn LabelProvider>>,
+    pub(crate) render_state: RenderCache,
     pub(crate) default_field_style: Style,
     pub(crate) invalid_field_style: Style,
+    pub(crate) warning_field_style: Style,
     pub(crate) hovered_field_style: Style,
     pub(crate) active_field_style: Style,
+    pub(crate) unfocused_style: Style,
+    pub(crate) invalid_char_style: Style,
+}
+
+// Forms must be able to live in application state that's shared across threads.
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Form>();
+};
+
+impl<K> fmt::Debug for Form<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Form")
+            .field("selected", &self.selected)
+            .field("fields", &self.fields)
+            .field("submitted", &self.submitted)
+            .field("validation_mode", &self.validation_mode)
+            .field("normalize", &self.normalize)
+            .field("wrap", &self.wrap)
+            .field("esc", &self.esc)
+            .field("keymap", &self.keymap)
+            .field("show_help", &self.show_help)
+            .field("show_tooltip", &self.show_tooltip)
+            .field("show_hints", &self.show_hints)
+            .field("select_display", &self.select_display)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Default for Form {
     fn default() -> Self {
+        Self::with_fields(Vec::new(), Vec::new())
+    }
+}
+
+impl<K> Form<K> {
+    /// A form with default settings and `fields`, keyed by `keys`.
+    fn with_fields(fields: Vec<FieldBuffer>, keys: Vec<K>) -> Self {
         Self {
             selected: FormSelection::NoSelection,
-            fields: Vec::new(),
+            fields,
+            keys,
             submitted: false,
-            validation_fn: Box::new(|f| !f.is_empty()),
+            validation_fn: bool_validator(|f| !f.is_empty()),
+            validation_mode: ValidationMode::OnSubmit,
+            normalize: Normalize::NONE,
+            wrap: WrapBehavior::Wrap,
+            esc: EscBehavior::Demote,
+            initial_focus: InitialFocus::NoSelection,
+            submit_on_enter: false,
+            last_action_escape: false,
+            value_before_active: None,
+            keymap: KeyMap::default(),
+            show_help: false,
+            show_tooltip: false,
+            show_hints: false,
+            sanitize: true,
+            focused: true,
+            select_display: SelectDisplay::Popup,
+            title_alignment: Alignment::Left,
+            title_position: Position::Top,
+            border_type: BorderType::Rounded,
+            field_borders: [(BorderType::Rounded, Borders::ALL); 5],
+            padding: Padding::zero(),
+            suggestions: Vec::new(),
+            browser: None,
+            composition: String::new(),
+            all_selected: false,
+            suggestion: None,
+            history_index: None,
+            hints: Vec::new(),
+            recording: None,
+            on_focus_change: None,
+            on_layout_change: None,
+            labels: None,
+            render_state: RenderCache::default(),
             default_field_style: Style::default(),
             invalid_field_style: Style::default().red().bold(),
+            warning_field_style: Style::default().yellow(),
             hovered_field_style: Style::default().cyan(),
             active_field_style: Style::default().cyan().bold(),
+            unfocused_style: Style::default().dim(),
+            invalid_char_style: Style::default().red().underlined(),
         }
     }
 }
@@ -165,31 +884,124 @@ impl Default for Form {
 impl Form {
     /// Create a new [`Form`] from a slice of field titles and a validator function.
     /// `validation_fn` is used to mark fields as either valid or invalid when `.status()` is called.
-    pub fn new(fields: &[&str], validation_fn: impl Fn(&str) -> bool + 'static) -> Self {
-        let fields = fields
+    pub fn new(
+        fields: &[&str],
+        validation_fn: impl Fn(&str) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        let fields: Vec<_> = fields
             .iter()
-            .map(|&title| FieldBuffer {
-                name: title.to_string(),
-                val: String::new(),
-            })
+            .map(|&title| FieldBuffer::new(title, ""))
             .collect();
 
         Self {
-            fields,
-            validation_fn: Box::new(validation_fn),
-            ..Default::default()
+            validation_fn: bool_validator(validation_fn),
+            ..Self::from(fields)
         }
     }
+}
 
+impl<K: FieldKey> Form<K> {
     /// Returns a tui [`Widget`](ratatui::widgets::Widget) to be used for rendering with
     /// [`render_frame`][ratatui::terminal::Frame::render_widget].
+    ///
+    /// The layout of the fields and the cells drawn for each field are cached, so redrawing an idle
+    /// form every tick is cheap. The layout is reused until the form changes or is rendered to a
+    /// different area, and a field's cells until its value, the selection or the form's style
+    /// changes. Only the cells a field paints are cached, so whatever is drawn underneath it still
+    /// shows through. Fields drawn with [`FieldDef::render_with`], and all fields while the form
+    /// has a [`LabelProvider`], are drawn afresh every frame, as their output can change without
+    /// the form knowing.
+    ///
+    /// Scrolling is worked out from the area each time the form is rendered, so when the terminal
+    /// is resized the selected field and the cursor stay in view, and fields scrolled out of view
+    /// come back once there's room for them again. Nothing is drawn into an empty area.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tui_form_widget::{test_utils, Form, FormSelection};
+    /// let mut form = Form::from(vec!["Host", "Port", "User"]);
+    /// form.select(FormSelection::Active(2));
+    /// let buf = test_utils::render(&form, 10, 3);
+    /// assert_eq!(test_utils::lines(&buf)[0], "╭User────╮");
+    /// let buf = test_utils::render(&form, 10, 9);
+    /// assert_eq!(test_utils::lines(&buf)[0], "╭Host────╮");
+    /// ```
     pub fn widget(&self) -> impl Widget + '_ {
         Renderer::new(self)
     }
 
-    /// Change current selection of the form.
+    /// Returns a tui [`Widget`](ratatui::widgets::Widget) that renders the form as a `width` x
+    /// `height` modal centered in the area it's rendered to. The area behind the modal is cleared,
+    /// so it can be drawn over an existing UI.
+    pub fn popup_widget(&self, width: u16, height: u16) -> impl Widget + '_ {
+        PopupRenderer::new(self, width, height)
+    }
+
+    /// Change current selection of the form. Selecting a field the form doesn't have is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crossterm::event::KeyCode;
+    /// # use tui_form_widget::{Form, FormSelection};
+    /// let mut form = Form::from(vec!["Name", "Email"]);
+    /// form.select(FormSelection::Active(5));
+    /// assert_eq!(*form.selected(), FormSelection::NoSelection);
+    ///
+    /// let mut form = Form::default();
+    /// form.input(KeyCode::Enter);
+    /// assert_eq!(*form.selected(), FormSelection::NoSelection);
+    /// ```
     pub fn select(&mut self, s: FormSelection) {
-        self.selected = s;
+        let input = RecordedInput::Select(s.clone());
+        self.recorded(|| input, |form| form.set_selection(s))
+    }
+
+    fn set_selection(&mut self, s: FormSelection) {
+        if let FormSelection::Hovered(i) | FormSelection::Active(i) = s {
+            if i >= self.fields.len() {
+                return;
+            }
+        }
+        if s == self.selected {
+            return;
+        }
+        // only the fields leaving and gaining the selection look different
+        for field in [&self.selected, &s] {
+            if let FormSelection::Hovered(i) | FormSelection::Active(i) = field {
+                self.render_state.invalidate_field(*i);
+            }
+        }
+        self.composition.clear();
+        self.all_selected = false;
+        self.show_tooltip = false;
+        self.browser = None;
+        if let FormSelection::Active(prev) = self.selected {
+            if let Some(fb) = self.fields.get_mut(prev) {
+                fb.touched = true;
+            }
+        }
+        if let FormSelection::Active(i) = s {
+            self.suggestions.clear();
+            self.history_index = None;
+            let fb = &mut self.fields[i];
+            self.value_before_active = Some(SecretString(fb.val.as_str().to_string()));
+            if let FieldKind::Select {
+                options,
+                highlighted,
+            } = &mut fb.kind
+            {
+                *highlighted = options
+                    .iter()
+                    .position(|o| o == fb.val.as_str())
+                    .unwrap_or(0);
+            }
+        }
+        let prev = std::mem::replace(&mut self.selected, s);
+        if let Some(on_focus_change) = &self.on_focus_change {
+            on_focus_change(&prev, &self.selected);
+        }
     }
 
     /// Get current selection state of the form.
@@ -198,141 +1010,1638 @@ impl Form {
     }
 
     /// Submits form and returns status of fields.
-    pub fn submit(&mut self) -> FormFieldStatus {
-        self.submitted = true;
+    ///
+    /// Values are [normalized](Normalize) first. If every field is valid, their values are
+    /// recorded in the history of fields with history enabled. Fields with
+    /// [warnings](Field::warning) are still valid, so check for them if the user should confirm
+    /// the values first.
+    pub fn submit(&mut self) -> FormFieldStatus<'_> {
+        self.recorded(|| RecordedInput::Submit, Self::submit_values);
         self.status()
     }
 
-    /// Returns the state of all fields in the form. Uses a [`Field`] struct to indicate whether or
-    /// not each field's buffer is valid.
-    pub fn status(&self) -> FormFieldStatus {
-        if self.submitted {
-            self.fields
-                .iter()
-                .map(|fb| {
-                    if (self.validation_fn)(&fb.val) {
-                        Field::valid(&fb.name, &fb.val)
-                    } else {
-                        Field::invalid(&fb.name, &fb.val)
-                    }
-                })
-                .collect()
-        } else {
-            self.fields
+    fn submit_values(&mut self) {
+        self.changed();
+        self.submitted = true;
+        for i in 0..self.fields.len() {
+            let fb = &self.fields[i];
+            let multiline = matches!(fb.kind, FieldKind::Custom(_) | FieldKind::TextArea { .. });
+            let normalize = match fb.normalize {
+                Some(normalize) => normalize,
+                None if fb.secret || multiline => Normalize::NONE,
+                None => self.normalize,
+            };
+            let normalized = match fb.kind {
+                FieldKind::Custom(_) | FieldKind::TextArea { .. } => {
+                    normalize.apply_lines(fb.val.as_str())
+                }
+                _ => normalize.apply(fb.val.as_str()),
+            };
+            if let Some(val) = normalized {
+                self.fields[i].set_value(val);
+                self.edited(i);
+            }
+            let fb = &self.fields[i];
+            if let Some(val) = url::with_default_scheme(fb.val.as_str(), &fb.default_scheme) {
+                self.fields[i].set_value(val);
+                self.edited(i);
+            }
+        }
+        if self.fields.iter().all(|fb| self.validate(fb)) {
+            for fb in self.fields.iter_mut().filter(|fb| !fb.secret) {
+                history::record(&mut fb.history, fb.val.as_str(), fb.history_limit);
+            }
+        }
+    }
+
+    /// Returns the submitted values of fields with history enabled.
+    pub fn history(&self) -> FormHistory {
+        FormHistory {
+            fields: self
+                .fields
                 .iter()
-                .map(|fb| Field::valid(&fb.name, &fb.val))
-                .collect()
+                .filter(|fb| fb.history_limit > 0)
+                .map(|fb| (fb.name.clone(), fb.history.clone()))
+                .collect(),
         }
     }
 
-    /// Handle default input for the form.
-    pub fn input(&mut self, key: KeyCode) {
-        if let FormSelection::Active(i) = self.selected {
-            match key {
-                KeyCode::Enter => self.next_field(),
-                KeyCode::Esc => self.select(FormSelection::Hovered(i)),
-                KeyCode::Backspace => self.pop_field(i),
-                KeyCode::Char(ch) => self.append_field(ch, i),
-                _ => {}
-            }
-        } else {
-            match key {
-                KeyCode::Esc => self.select(FormSelection::NoSelection),
-                KeyCode::Char('j') => self.next_field(),
-                KeyCode::Char('k') => self.prev_field(),
-                KeyCode::Enter => {
-                    if let FormSelection::Hovered(i) = self.selected {
-                        self.selected = FormSelection::Active(i)
-                    } else {
-                        self.selected = FormSelection::Active(0)
-                    }
-                }
-                _ => {}
+    /// Restores previously saved history, e.g. from an earlier session. Entries for fields that
+    /// aren't in the form or don't have history enabled are ignored.
+    pub fn load_history(&mut self, history: FormHistory) {
+        let mut fields = history.fields;
+        for fb in self.fields.iter_mut().filter(|fb| fb.history_limit > 0) {
+            if let Some(mut values) = fields.remove(&fb.name) {
+                values.drain(..values.len().saturating_sub(fb.history_limit));
+                fb.history = values;
             }
         }
     }
 
-    fn pop_field(&mut self, field: usize) {
-        self.fields[field].val.pop();
+    /// Returns the value of `field`, or `None` if there's no such field.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tui_form_widget::Form;
+    /// let form = Form::from(vec![("Name", "Ferris")]);
+    /// assert_eq!(form.value("Name"), Some("Ferris"));
+    /// assert_eq!(form.value(1), None);
+    /// ```
+    pub fn value<'a>(&self, field: impl Into<FieldRef<'a, K>>) -> Option<&str> {
+        let i = self.field_index(field.into())?;
+        Some(self.fields[i].val.as_str())
     }
 
-    fn append_field(&mut self, ch: char, field: usize) {
-        self.fields[field].val.push(ch)
+    /// Returns the [key](FieldKey) of `field`, or `None` if there's no such field.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tui_form_widget::{FieldDef, FieldKey, Form};
+    /// #[derive(Clone, Copy, Debug, PartialEq)]
+    /// enum FieldId {
+    ///     Host,
+    ///     Port,
+    /// }
+    ///
+    /// impl FieldKey for FieldId {}
+    ///
+    /// let form = Form::from(vec![
+    ///     FieldDef::text("Host").key(FieldId::Host),
+    ///     FieldDef::number("Port").key(FieldId::Port),
+    /// ]);
+    /// assert_eq!(form.key(1), Some(&FieldId::Port));
+    /// assert_eq!(form.key("Host"), Some(&FieldId::Host));
+    /// ```
+    pub fn key<'a>(&self, field: impl Into<FieldRef<'a, K>>) -> Option<&K> {
+        let i = self.field_index(field.into())?;
+        Some(&self.keys[i])
     }
 
-    /// Append a char to the active field (if one is)
-    pub fn append_selection(&mut self, ch: char) {
-        match self.selected() {
-            FormSelection::NoSelection => {}
-            FormSelection::Hovered(_) => {}
-            FormSelection::Active(i) => self.append_field(ch, *i),
-        }
+    /// Area `field` was drawn in when the form was last rendered, or `None` if it wasn't drawn,
+    /// e.g. because it was scrolled out of view. See also
+    /// [`on_layout_change`](Self::on_layout_change).
+    pub fn field_area<'a>(&self, field: impl Into<FieldRef<'a, K>>) -> Option<Rect> {
+        let i = self.field_index(field.into())?;
+        let state = self.render_state.lock();
+        state
+            .layout
+            .iter()
+            .find(|(j, _)| *j == i)
+            .map(|(_, area)| *area)
     }
 
-    /// Remove a char frome the active field (if one is)
-    pub fn pop_selection(&mut self) {
-        match self.selected() {
-            FormSelection::NoSelection => {}
-            FormSelection::Hovered(_) => {}
-            FormSelection::Active(i) => self.pop_field(*i),
-        }
+    /// Returns the state of all fields in the form. Uses a [`Field`] struct to indicate whether or
+    /// not each field's buffer is valid.
+    ///
+    /// See [`Form::iter_fields`] to go through the fields without collecting them, or
+    /// [`Form::field`] for a single field.
+    pub fn status(&self) -> FormFieldStatus<'_> {
+        self.iter_fields().collect()
     }
 
-    /// De(select / activate) current field
-    pub fn deselect(&mut self) {
-        self.selected = FormSelection::NoSelection
+    /// Returns the state of each field in the form, like [`Form::status`] but without collecting
+    /// them into a `Vec`.
+    pub fn iter_fields(&self) -> impl Iterator<Item = Field<'_>> + '_ {
+        self.fields.iter().map(|fb| self.field_status(fb))
     }
 
-    /// Move to next field. Retains previous hovered or activated state.
-    pub fn next_field(&mut self) {
-        self.selected = match self.selected {
-            FormSelection::NoSelection => FormSelection::Hovered(0),
-            FormSelection::Hovered(i) => {
-                FormSelection::Hovered((i + 1).rem_euclid(self.fields.len()))
-            }
-            FormSelection::Active(i) => {
-                FormSelection::Active((i + 1).rem_euclid(self.fields.len()))
-            }
+    /// Returns the state of `field`, or `None` if there's no such field. Only that field is
+    /// checked, unlike with [`Form::status`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tui_form_widget::{FieldDef, Form};
+    /// let mut form = Form::from(vec![FieldDef::text("Name"), FieldDef::text("Email")]);
+    /// form.submit();
+    /// let name = form.field("Name").unwrap();
+    /// assert!(!name.is_valid());
+    /// assert!(form.field("Phone").is_none());
+    /// ```
+    pub fn field<'a>(&self, field: impl Into<FieldRef<'a, K>>) -> Option<Field<'_>> {
+        let i = self.field_index(field.into())?;
+        Some(self.field_status(&self.fields[i]))
+    }
+
+    fn field_status<'a>(&'a self, fb: &'a FieldBuffer) -> Field<'a> {
+        let field = if self.is_invalid(fb) {
+            Field::invalid(fb, self.validation_error(fb))
+        } else {
+            Field::valid(fb)
+        };
+        Field {
+            warning: self.warning(fb),
+            ..field
         }
     }
 
-    /// Move to previous field. Retains previous hovered or activated state.
-    pub fn prev_field(&mut self) {
-        self.selected = match self.selected {
-            FormSelection::NoSelection => FormSelection::Hovered(0),
-            FormSelection::Hovered(i) => {
-                let i = if i == 0 { self.fields.len() - 1 } else { i - 1 };
-                FormSelection::Hovered(i)
+    /// Consumes the form, returning the owned name, value and validity of each field. Validity is
+    /// the same as reported by [`Form::status`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tui_form_widget::{FieldDef, Form};
+    /// let mut form = Form::from(vec![FieldDef::text("Name").value("Ferris")]);
+    /// form.submit();
+    ///
+    /// let values = form.into_values();
+    /// let handle = std::thread::spawn(move || values[0].value() == "Ferris");
+    /// assert!(handle.join().unwrap());
+    /// ```
+    pub fn into_values(mut self) -> Vec<SubmittedField<K>> {
+        let status: Vec<_> = self
+            .iter_fields()
+            .map(|field| {
+                (
+                    field.is_valid(),
+                    field.error().map(str::to_string),
+                    field.warning().map(str::to_string),
+                )
+            })
+            .collect();
+        let keys = std::mem::take(&mut self.keys);
+        self.fields
+            .iter_mut()
+            .zip(keys)
+            .zip(status)
+            .map(|((fb, key), (valid, error, warning))| SubmittedField {
+                key,
+                name: std::mem::take(&mut fb.name),
+                value: fb.val.take(),
+                sensitive: fb.sensitive,
+                valid,
+                error,
+                warning,
+                metadata: std::mem::take(&mut fb.metadata),
+                value_type: fb.value_type,
+            })
+            .collect()
+    }
+
+    /// Consumes the form, returning the value of each field keyed by its name. If several fields
+    /// share a name, the last one wins.
+    ///
+    /// Sensitive fields are included, see [`Form::to_public_map`] to leave them out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tui_form_widget::{FieldDef, Form};
+    /// let form = Form::from(vec![
+    ///     FieldDef::text("User").value("ferris"),
+    ///     FieldDef::text("Password").value("hunter2").secret(),
+    /// ]);
+    /// let values = form.into_map();
+    /// assert_eq!(values["User"], "ferris");
+    /// assert_eq!(values["Password"], "hunter2");
+    /// ```
+    pub fn into_map(mut self) -> HashMap<String, String> {
+        self.fields
+            .iter_mut()
+            .map(|fb| (std::mem::take(&mut fb.name), fb.val.take()))
+            .collect()
+    }
+
+    /// Returns the value of each field keyed by its name. If several fields share a name, the last
+    /// one wins.
+    ///
+    /// Sensitive fields are included, see [`Form::to_public_map`] to leave them out.
+    pub fn to_map(&self) -> HashMap<String, String> {
+        self.fields
+            .iter()
+            .map(|fb| (fb.name.clone(), fb.val.as_str().to_string()))
+            .collect()
+    }
+
+    /// Like [`Form::to_map`], but leaves out [sensitive](crate::FieldDef::sensitive) fields, e.g.
+    /// for logging or saving the values.
+    pub fn to_public_map(&self) -> HashMap<String, String> {
+        self.fields
+            .iter()
+            .filter(|fb| !fb.sensitive)
+            .map(|fb| (fb.name.clone(), fb.val.as_str().to_string()))
+            .collect()
+    }
+
+    /// Returns `true` if `fb` should be marked as invalid given the form's validation mode.
+    pub(crate) fn is_invalid(&self, fb: &FieldBuffer) -> bool {
+        self.is_checked(fb) && !self.validate(fb)
+    }
+
+    /// Returns the warning about `fb`, if it has one and should be reported given the form's
+    /// validation mode.
+    pub(crate) fn warning<'b>(&self, fb: &'b FieldBuffer) -> Option<&'b str> {
+        if !self.is_checked(fb) {
+            return None;
+        }
+        let warning = fb.warned.get_or_init(|| {
+            let warning = fb.warning.as_ref()?;
+            warning(fb.val.as_str()).err().flatten()
+        });
+        warning.as_deref()
+    }
+
+    /// Returns `true` if problems with `fb` should be reported given the form's validation mode.
+    fn is_checked(&self, fb: &FieldBuffer) -> bool {
+        self.submitted || self.validation_mode == ValidationMode::Live || fb.checked
+    }
+
+    /// Returns `true` if problems with `fb` should be rendered. Until the form is submitted, only
+    /// fields the user has touched or that were validated on their own are.
+    pub(crate) fn shows_problems(&self, fb: &FieldBuffer) -> bool {
+        self.submitted || fb.touched || fb.checked
+    }
+
+    /// Runs the validators of a single field, e.g. when leaving it, without submitting the form.
+    /// From then on the field is marked as invalid whenever it is, regardless of the
+    /// [`ValidationMode`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tui_form_widget::{Error, Form};
+    /// let mut form = Form::new(&["Name", "Email"], |val| !val.is_empty());
+    /// assert_eq!(
+    ///     form.validate_field("Email"),
+    ///     Err(Error::Invalid { field: "Email".into(), message: None })
+    /// );
+    /// assert!(!form.status()[1].is_valid());
+    /// assert!(form.status()[0].is_valid());
+    /// ```
+    pub fn validate_field<'a>(&mut self, field: impl Into<FieldRef<'a, K>>) -> Result<(), Error> {
+        let i = self.field_index(field.into()).ok_or(Error::UnknownField)?;
+        self.recorded(
+            || RecordedInput::ValidateField(i),
+            |form| form.check_field(i),
+        )
+    }
+
+    fn check_field(&mut self, i: usize) -> Result<(), Error> {
+        self.changed();
+        let fb = &mut self.fields[i];
+        fb.validity.take();
+        fb.warned.take();
+        fb.checked = true;
+
+        let fb = &self.fields[i];
+        if self.validate(fb) {
+            Ok(())
+        } else {
+            Err(Error::Invalid {
+                field: fb.name.clone(),
+                message: self.validation_error(fb).map(str::to_string),
+            })
+        }
+    }
+
+    /// Returns the index of `field`, if it's in the form.
+    pub(crate) fn field_index(&self, field: FieldRef<'_, K>) -> Option<usize> {
+        match field {
+            FieldRef::Index(i) => (i < self.fields.len()).then_some(i),
+            FieldRef::Name(name) => self.fields.iter().position(|fb| fb.name == name),
+            FieldRef::Key(key) => self.keys.iter().position(|k| *k == key),
+        }
+    }
+
+    /// Returns whether `fb` is valid, only running its validators if its value, or the value of the
+    /// field it's linked to, changed since they last ran.
+    fn validate(&self, fb: &FieldBuffer) -> bool {
+        self.validation(fb).is_ok()
+    }
+
+    /// Returns the message describing why `fb` is invalid, if it is and there is one.
+    pub(crate) fn validation_error<'b>(&self, fb: &'b FieldBuffer) -> Option<&'b str> {
+        self.validation(fb).as_ref().err()?.as_deref()
+    }
+
+    fn validation<'b>(&self, fb: &'b FieldBuffer) -> &'b Validation {
+        fb.validity.get_or_init(|| self.run_validators(fb))
+    }
+
+    /// Runs the field's validator, falling back to the form's validator, and any linked validator
+    /// against the current value of the field it's linked to. Values with chars that aren't
+    /// allowed, values of number fields that aren't numbers in range, and values of URL fields that
+    /// aren't URLs, are invalid without running any validators.
+    fn run_validators(&self, fb: &FieldBuffer) -> Validation {
+        if let Some(allowed) = &fb.allowed_chars {
+            if !fb.val.as_str().chars().all(|ch| allowed(ch)) {
+                return Err(None);
+            }
+        }
+        if let Some(schemes) = &fb.url_schemes {
+            let val = url::with_default_scheme(fb.val.as_str(), &fb.default_scheme);
+            url::validate(val.as_deref().unwrap_or(fb.val.as_str()), schemes).map_err(Some)?;
+        }
+        if let (FieldKind::Number { range, .. }, false) = (&fb.kind, fb.val.is_empty()) {
+            if !fb.val.as_str().parse().is_ok_and(|n| range.contains(&n)) {
+                return Err(None);
+            }
+        }
+        match &fb.validator {
+            Some(validator) => validator(fb.val.as_str())?,
+            None => (self.validation_fn)(fb.val.as_str())?,
+        }
+
+        let Some((name, validator)) = &fb.link else {
+            return Ok(());
+        };
+        match self.fields.iter().find(|other| other.name == *name) {
+            Some(other) if !validator(fb.val.as_str(), other.val.as_str()) => Err(None),
+            _ => Ok(()),
+        }
+    }
+
+    /// Drops the cached render of the form, so it's redrawn on the next frame.
+    pub(crate) fn changed(&mut self) {
+        self.render_state.invalidate();
+    }
+
+    /// Marks `field` as touched after its value changed, and its cached validity and render, and
+    /// those of any fields linked to it, as out of date.
+    fn edited(&mut self, field: usize) {
+        self.fields[field].touched = true;
+        self.fields[field].validity.take();
+        self.fields[field].warned.take();
+        self.render_state.invalidate_field(field);
+        for i in 0..self.fields.len() {
+            let linked = matches!(
+                &self.fields[i].link,
+                Some((name, _)) if *name == self.fields[field].name
+            );
+            if linked {
+                self.fields[i].validity.take();
+                self.render_state.invalidate_field(i);
+            }
+        }
+    }
+
+    /// Handle default input for the form, using the form's [`KeyMap`].
+    pub fn input(&mut self, key: KeyCode) -> FormEvent {
+        self.recorded(|| RecordedInput::Key(key), |form| form.handle_input(key))
+    }
+
+    /// Handle a key event, like [`Form::input`] but also handling keys pressed with modifiers,
+    /// which are bound as [chords](KeyMap::bind_chord). Key releases and unbound chords are
+    /// ignored, so e.g. `Ctrl+A` isn't typed as `a`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    /// # use tui_form_widget::Form;
+    /// let mut form = Form::from(vec!["Name"]);
+    /// form.focus_first();
+    /// form.type_str("Ferris Crab");
+    /// form.input_event(KeyEvent::new(KeyCode::Left, KeyModifiers::CONTROL));
+    /// form.type_str("the ");
+    /// assert_eq!(form.status()[0].value(), "Ferris the Crab");
+    /// ```
+    pub fn input_event(&mut self, event: KeyEvent) -> FormEvent {
+        if event.kind == KeyEventKind::Release {
+            return FormEvent::Ignored;
+        }
+        // Shift is part of the char typed, and only matters for other keys if it's bound
+        let shifted = !matches!(event.code, KeyCode::Char(_))
+            && self
+                .keymap
+                .chord_action(event.modifiers, event.code)
+                .is_some();
+        let modifiers = match shifted {
+            true => event.modifiers,
+            false => event.modifiers.difference(KeyModifiers::SHIFT),
+        };
+        if modifiers.is_empty() {
+            return self.input(event.code);
+        }
+        self.recorded(
+            || RecordedInput::Event(event),
+            |form| form.handle_chord(modifiers, event.code),
+        )
+    }
+
+    fn handle_chord(&mut self, modifiers: KeyModifiers, key: KeyCode) -> FormEvent {
+        if !self.focused || self.show_help || !matches!(self.selected, FormSelection::Active(_)) {
+            return FormEvent::Ignored;
+        }
+        self.all_selected = false;
+        if let FormSelection::Active(i) = self.selected {
+            let event = self.custom_event(i, |widget| widget.handle_chord(modifiers, key));
+            if let Some(event) = event {
+                self.changed();
+                return event;
+            }
+        }
+        match self.keymap.chord_action(modifiers, key) {
+            Some(action) => {
+                self.changed();
+                self.last_action_escape = false;
+                self.perform(action, false)
+            }
+            None => FormEvent::Ignored,
+        }
+    }
+
+    fn handle_input(&mut self, key: KeyCode) -> FormEvent {
+        let active = match self.selected {
+            FormSelection::Active(i) => Some(i),
+            _ => None,
+        };
+        let all_selected = self.all_selected;
+        let event = self.handle_key(key);
+        // keys can move the cursor or highlight without changing the value, so the active field
+        // is drawn again unless the key was ignored
+        let drawn_differently = event != FormEvent::Ignored || all_selected != self.all_selected;
+        if let (Some(i), true) = (active, drawn_differently) {
+            self.render_state.invalidate_field(i);
+        }
+        event
+    }
+
+    fn handle_key(&mut self, key: KeyCode) -> FormEvent {
+        if !self.focused {
+            return FormEvent::Ignored;
+        }
+        if self.show_help {
+            return match self.keymap.navigation_action(key) {
+                Some(Action::ToggleHelp | Action::Escape) => {
+                    self.show_help = false;
+                    FormEvent::Handled
+                }
+                _ => FormEvent::Ignored,
+            };
+        }
+        // any key closes the tooltip, `?` again opens the full help
+        if std::mem::take(&mut self.show_tooltip) {
+            match self.keymap.navigation_action(key) {
+                Some(Action::ToggleHelp) => {
+                    self.show_help = true;
+                    return FormEvent::Handled;
+                }
+                Some(Action::Escape) => return FormEvent::Handled,
+                _ => {}
+            }
+        }
+
+        let action = if let FormSelection::Active(i) = self.selected {
+            if let Some(event) = self
+                .select_all_input(i, key)
+                .or_else(|| self.browser_input(i, key))
+                .or_else(|| self.custom_input(i, key))
+                .or_else(|| self.select_input(i, key))
+                .or_else(|| self.ip_input(i, key))
+                .or_else(|| self.suggestion_input(i, key))
+                .or_else(|| self.textarea_input(i, key))
+                .or_else(|| self.history_input(i, key))
+            {
+                return event;
+            }
+            match (self.keymap.editing_action(key), key) {
+                (Some(action), _) => action,
+                (None, KeyCode::Char(ch)) => {
+                    self.last_action_escape = false;
+                    self.append_field(ch, i);
+                    return FormEvent::Handled;
+                }
+                (None, _) => return FormEvent::Ignored,
+            }
+        } else {
+            match self.keymap.navigation_action(key) {
+                Some(action) => action,
+                None => return FormEvent::Ignored,
+            }
+        };
+
+        let last_action_escape =
+            std::mem::replace(&mut self.last_action_escape, action == Action::Escape);
+        self.perform(action, last_action_escape)
+    }
+
+    /// Clears the value of a field that's [all selected](Action::SelectAll) before text is typed
+    /// into it, or when it's deleted, returning `None` for keys that should be handled as usual.
+    /// Any key deselects the value.
+    fn select_all_input(&mut self, field: usize, key: KeyCode) -> Option<FormEvent> {
+        if !std::mem::take(&mut self.all_selected) {
+            return None;
+        }
+        let action = self.keymap.editing_action(key);
+        let deletes = matches!(action, Some(Action::DeleteBackward | Action::DeleteForward));
+        let types = action.is_none() && matches!(key, KeyCode::Char(_));
+        if !deletes && !types {
+            return None;
+        }
+        self.fields[field].set_value(String::new());
+        self.edited(field);
+        self.refresh_suggestions(field);
+        deletes.then_some(FormEvent::Handled)
+    }
+
+    /// Passes keys to custom fields, returning `None` for keys they ignore.
+    fn custom_input(&mut self, field: usize, key: KeyCode) -> Option<FormEvent> {
+        self.custom_event(field, |widget| widget.handle_input(key))
+    }
+
+    /// Passes input to the widget of a custom field with `handle`, syncing the field's value with
+    /// the widget's. Returns `None` if the field isn't custom or the widget ignored the input.
+    fn custom_event(
+        &mut self,
+        field: usize,
+        handle: impl FnOnce(&mut dyn FieldWidget) -> FormEvent,
+    ) -> Option<FormEvent> {
+        let fb = &mut self.fields[field];
+        let FieldKind::Custom(widget) = &mut fb.kind else {
+            return None;
+        };
+
+        let event = handle(widget.as_mut());
+        if event == FormEvent::Ignored {
+            return None;
+        }
+        let val = widget.value();
+        if val != fb.val.as_str() {
+            fb.val.set(val);
+            self.edited(field);
+        }
+        Some(event)
+    }
+
+    /// Handles keys specific to select fields, returning `None` for keys that should be handled as
+    /// usual.
+    fn select_input(&mut self, field: usize, key: KeyCode) -> Option<FormEvent> {
+        let fb = &mut self.fields[field];
+        let FieldKind::Select {
+            options,
+            highlighted,
+        } = &mut fb.kind
+        else {
+            return None;
+        };
+
+        match key {
+            KeyCode::Down | KeyCode::Char('j') => {
+                *highlighted = (*highlighted + 1).min(options.len().saturating_sub(1))
+            }
+            KeyCode::Up | KeyCode::Char('k') => *highlighted = highlighted.saturating_sub(1),
+            KeyCode::Enter => {
+                if let Some(option) = options.get(*highlighted) {
+                    fb.val.set(option.clone());
+                    self.edited(field);
+                }
+                return None;
+            }
+            KeyCode::Char(_) | KeyCode::Backspace | KeyCode::Delete => {
+                return Some(FormEvent::Ignored)
+            }
+            _ => return None,
+        }
+        Some(FormEvent::Handled)
+    }
+
+    /// Handles typing digits into IP address fields, inserting the dots between the octets of IPv4
+    /// addresses, returning `None` for keys that should be handled as usual.
+    ///
+    /// Until the first dot is typed the value could still be an IPv6 address, like `2001:db8::1`,
+    /// so nothing is inserted.
+    fn ip_input(&mut self, field: usize, key: KeyCode) -> Option<FormEvent> {
+        let fb = &self.fields[field];
+        let (FieldKind::Ip, KeyCode::Char(ch)) = (&fb.kind, key) else {
+            return None;
+        };
+        let before = fb.val.before();
+        let ipv4 =
+            before.contains('.') && before.chars().all(|ch| ch.is_ascii_digit() || ch == '.');
+        if !ipv4 || fb.val.after().next().is_some() {
+            return None;
+        }
+
+        let octet = |before: &str| before[before.rfind('.').map_or(0, |i| i + 1)..].to_string();
+        let fits = |octet: &str, ch: char| {
+            octet.len() < 3 && format!("{octet}{ch}").parse().is_ok_and(|n: u32| n <= 255)
+        };
+        let mut dots = before.matches('.').count();
+        let current = octet(before);
+        match ch {
+            // typed after the dot was inserted automatically
+            '.' if current.is_empty() && dots > 0 => return Some(FormEvent::Handled),
+            '0'..='9' if !fits(&current, ch) => {
+                if dots == 3 {
+                    return Some(FormEvent::Ignored);
+                }
+                self.append_field('.', field);
+                dots += 1;
+            }
+            '0'..='9' => {}
+            _ => return None,
+        }
+
+        self.last_action_escape = false;
+        self.append_field(ch, field);
+        let current = octet(self.fields[field].val.before());
+        if dots < 3 && !fits(&current, '0') {
+            self.append_field('.', field);
+        }
+        Some(FormEvent::Handled)
+    }
+
+    /// Handles keys specific to textarea fields, returning `None` for keys that should be handled
+    /// as usual.
+    fn textarea_input(&mut self, field: usize, key: KeyCode) -> Option<FormEvent> {
+        let fb = &mut self.fields[field];
+        if !matches!(fb.kind, FieldKind::TextArea { .. }) {
+            return None;
+        }
+
+        match key {
+            KeyCode::Enter => {
+                self.last_action_escape = false;
+                self.append_field('\n', field);
+            }
+            KeyCode::Up => fb.val.move_up(),
+            KeyCode::Down => fb.val.move_down(),
+            _ => return None,
+        }
+        Some(FormEvent::Handled)
+    }
+
+    fn perform(&mut self, action: Action, last_action_escape: bool) -> FormEvent {
+        match (action, &self.selected) {
+            (Action::NextField, FormSelection::Active(i))
+                if self.submit_on_enter && *i + 1 == self.fields.len() =>
+            {
+                let valid = self.submit().iter().all(Field::is_valid);
+                return if valid {
+                    FormEvent::Submitted
+                } else {
+                    FormEvent::SubmitFailed
+                };
+            }
+            (Action::NextField, _) => return self.next_field(),
+            (Action::PrevField, _) => return self.prev_field(),
+            (Action::Activate, FormSelection::Hovered(i)) => self.select(FormSelection::Active(*i)),
+            (Action::Activate, FormSelection::NoSelection) => self.select(FormSelection::Active(0)),
+            (Action::Escape, FormSelection::Active(i)) => self.escape_active(*i),
+            (Action::Escape, _) => {
+                if self.esc != EscBehavior::DoubleEscDeselect || last_action_escape {
+                    self.select(FormSelection::NoSelection);
+                }
+            }
+            (Action::DeleteBackward, FormSelection::Active(i)) => self.pop_field(*i),
+            (Action::DeleteForward, FormSelection::Active(i)) => self.delete_forward(*i),
+            (Action::ClearField, FormSelection::Active(i)) => self.clear_field(*i),
+            (Action::MoveLeft, FormSelection::Active(i)) => _ = self.fields[*i].val.move_left(),
+            (Action::MoveRight, FormSelection::Active(i)) => _ = self.fields[*i].val.move_right(),
+            (Action::MoveHome, FormSelection::Active(i)) => self.fields[*i].val.move_home(),
+            (Action::MoveEnd, FormSelection::Active(i)) => self.fields[*i].val.move_end(),
+            (Action::MoveWordLeft, FormSelection::Active(i)) => {
+                self.fields[*i].val.move_word_left()
+            }
+            (Action::MoveWordRight, FormSelection::Active(i)) => {
+                self.fields[*i].val.move_word_right()
+            }
+            (Action::SelectAll, FormSelection::Active(i)) => {
+                let fb = &self.fields[*i];
+                self.all_selected = matches!(
+                    fb.kind,
+                    FieldKind::Text | FieldKind::TextArea { .. } | FieldKind::Number { .. }
+                ) && !fb.val.is_empty();
+            }
+            (Action::Increment, FormSelection::Active(i)) => return self.step_number(*i, 1, false),
+            (Action::Decrement, FormSelection::Active(i)) => {
+                return self.step_number(*i, -1, false)
+            }
+            (Action::IncrementLarge, FormSelection::Active(i)) => {
+                return self.step_number(*i, 1, true)
+            }
+            (Action::DecrementLarge, FormSelection::Active(i)) => {
+                return self.step_number(*i, -1, true)
+            }
+            (Action::Browse, FormSelection::Active(i)) if self.fields[*i].browse => {
+                self.suggestions.clear();
+                self.browser = Some(Browser::open(self.fields[*i].val.as_str()));
+            }
+            (Action::MoveFieldUp, FormSelection::Hovered(i)) if *i > 0 => {
+                let i = *i;
+                _ = self.move_field(i, i - 1);
+            }
+            (Action::MoveFieldDown, FormSelection::Hovered(i)) if *i + 1 < self.fields.len() => {
+                let i = *i;
+                _ = self.move_field(i, i + 1);
+            }
+            (Action::ToggleHelp, FormSelection::Hovered(i))
+                if self.fields[*i].tooltip.is_some() =>
+            {
+                self.show_tooltip = true;
+            }
+            (Action::ToggleHelp, _) => self.show_help = !self.show_help,
+            _ => return FormEvent::Ignored,
+        }
+        FormEvent::Handled
+    }
+
+    fn escape_active(&mut self, field: usize) {
+        if !self.suggestions.is_empty() {
+            self.suggestions.clear();
+            return;
+        }
+
+        match self.esc {
+            EscBehavior::ClearField if !self.fields[field].val.is_empty() => {
+                self.fields[field].set_value(String::new());
+                self.edited(field);
+                return;
+            }
+            EscBehavior::Revert => {
+                if let Some(mut val) = self.value_before_active.take() {
+                    self.fields[field].set_value(std::mem::take(&mut val.0));
+                    self.edited(field);
+                }
+            }
+            _ => {}
+        }
+        self.select(FormSelection::Hovered(field));
+    }
+
+    fn pop_field(&mut self, field: usize) {
+        self.fields[field].val.delete_backward();
+        self.edited(field);
+        self.history_index = None;
+        self.refresh_suggestions(field);
+    }
+
+    fn clear_field(&mut self, field: usize) {
+        self.fields[field].set_value(String::new());
+        self.edited(field);
+        self.history_index = None;
+        self.refresh_suggestions(field);
+    }
+
+    /// Adds `sign` times the step, or the large step, of a number field to its value, clamped to
+    /// its range. Empty values count as zero, and values that aren't numbers are left alone.
+    fn step_number(&mut self, field: usize, sign: i64, large: bool) -> FormEvent {
+        let fb = &mut self.fields[field];
+        let FieldKind::Number {
+            step,
+            large_step,
+            range,
+            ..
+        } = &fb.kind
+        else {
+            return FormEvent::Ignored;
+        };
+        let val = match fb.val.as_str() {
+            "" => Ok(0),
+            val => val.parse::<i64>(),
+        };
+        let Ok(val) = val else {
+            return FormEvent::Ignored;
+        };
+
+        let step = if large { *large_step } else { *step };
+        let stepped = val
+            .saturating_add(sign.saturating_mul(step))
+            .clamp(*range.start(), *range.end());
+        fb.val.set(stepped.to_string());
+        self.all_selected = false;
+        self.edited(field);
+        FormEvent::Handled
+    }
+
+    fn delete_forward(&mut self, field: usize) {
+        if self.fields[field].val.delete_forward().is_some() {
+            self.edited(field);
+            self.history_index = None;
+            self.refresh_suggestions(field);
+        }
+    }
+
+    fn append_field(&mut self, ch: char, field: usize) {
+        let fb = &mut self.fields[field];
+        if fb.transforms.is_empty() {
+            fb.val.insert(ch);
+        } else {
+            let mut text = ch.to_string();
+            for transform in &fb.transforms {
+                text = transform(&text);
+            }
+            if text.is_empty() {
+                return;
+            }
+            for ch in text.chars() {
+                fb.val.insert(ch);
+            }
+        }
+        self.edited(field);
+        self.history_index = None;
+        self.refresh_suggestions(field);
+
+        let fb = &self.fields[field];
+        let complete =
+            matches!(fb.auto_advance, Some(len) if fb.val.as_str().chars().count() >= len);
+        if complete && field + 1 < self.fields.len() {
+            self.select(FormSelection::Active(field + 1));
+        }
+    }
+
+    /// Handles recalling previous values with `Up`/`Down` in an empty field, returning `None` for
+    /// keys that should be handled as usual.
+    fn history_input(&mut self, field: usize, key: KeyCode) -> Option<FormEvent> {
+        let fb = &mut self.fields[field];
+        if fb.history.is_empty() || (self.history_index.is_none() && !fb.val.is_empty()) {
+            return None;
+        }
+
+        let last = fb.history.len() - 1;
+        self.history_index = match (key, self.history_index) {
+            (KeyCode::Up, None) => Some(last),
+            (KeyCode::Up, Some(i)) => Some(i.saturating_sub(1)),
+            (KeyCode::Down, Some(i)) if i < last => Some(i + 1),
+            (KeyCode::Down, _) => None,
+            _ => return None,
+        };
+        let val = match self.history_index {
+            Some(i) => fb.history[i].clone(),
+            None => String::new(),
+        };
+        fb.set_value(val);
+        self.edited(field);
+        Some(FormEvent::Handled)
+    }
+
+    fn refresh_suggestions(&mut self, field: usize) {
+        let fb = &self.fields[field];
+        self.suggestions = match &fb.suggestions {
+            Some(provider) => provider.suggest(fb.val.as_str()),
+            None => Vec::new(),
+        };
+        self.suggestion = None;
+    }
+
+    /// Handles keys while the file browser is open, returning `None` if it isn't.
+    fn browser_input(&mut self, field: usize, key: KeyCode) -> Option<FormEvent> {
+        match self.browser.as_mut()?.input(key) {
+            BrowserEvent::Handled => {}
+            BrowserEvent::Selected(path) => {
+                self.browser = None;
+                self.fields[field].set_value(path);
+                self.edited(field);
+            }
+            BrowserEvent::Closed => self.browser = None,
+        }
+        Some(FormEvent::Handled)
+    }
+
+    /// Handles navigating and accepting suggestions, returning `None` for keys that should be
+    /// handled as usual.
+    fn suggestion_input(&mut self, field: usize, key: KeyCode) -> Option<FormEvent> {
+        if self.suggestions.is_empty() {
+            return None;
+        }
+
+        let last = self.suggestions.len() - 1;
+        match (key, self.suggestion) {
+            (KeyCode::Down, None) => self.suggestion = Some(0),
+            (KeyCode::Down, Some(i)) => self.suggestion = Some((i + 1).min(last)),
+            (KeyCode::Up, Some(0)) => self.suggestion = None,
+            (KeyCode::Up, Some(i)) => self.suggestion = Some(i - 1),
+            (KeyCode::Tab, i) | (KeyCode::Enter, i @ Some(_)) => {
+                let suggestion = self.suggestions.swap_remove(i.unwrap_or(0));
+                self.fields[field].set_value(suggestion);
+                self.edited(field);
+                self.suggestions.clear();
+                self.suggestion = None;
             }
-            FormSelection::Active(i) => {
-                let i = if i == 0 { self.fields.len() - 1 } else { i - 1 };
-                FormSelection::Active(i)
+            _ => return None,
+        }
+        Some(FormEvent::Handled)
+    }
+
+    /// Set the text an IME is composing for the active field, e.g. from the preedit events of the
+    /// app's input source. It's shown underlined at the cursor without changing the field's value
+    /// until [`Form::commit_composition`] is called. An empty `text` cancels the composition,
+    /// as does changing the selection.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tui_form_widget::{Form, FormSelection};
+    /// let mut form = Form::new(&["Name"], |_| true);
+    /// form.select(FormSelection::Active(0));
+    /// form.set_composition("にほ");
+    /// assert_eq!(form.status()[0].value(), "");
+    ///
+    /// form.set_composition("日本");
+    /// form.commit_composition();
+    /// assert_eq!(form.status()[0].value(), "日本");
+    /// ```
+    pub fn set_composition(&mut self, text: &str) {
+        self.recorded(
+            || RecordedInput::SetComposition(text.to_string()),
+            |form| {
+                if let FormSelection::Active(_) = form.selected {
+                    form.changed();
+                    form.composition.clear();
+                    form.composition.push_str(text);
+                }
+            },
+        )
+    }
+
+    /// Insert the text being composed by an IME into the active field at the cursor, see
+    /// [`Form::set_composition`].
+    pub fn commit_composition(&mut self) {
+        self.recorded(
+            || RecordedInput::CommitComposition,
+            |form| {
+                form.changed();
+                let composition = std::mem::take(&mut form.composition);
+                if let FormSelection::Active(i) = form.selected {
+                    for ch in composition.chars() {
+                        form.append_field(ch, i);
+                    }
+                }
+            },
+        )
+    }
+
+    /// Type `text` into the active field as if each char was typed, so transforms, suggestions and
+    /// key bindings apply as usual. A `'\n'` is handled as `Enter`. Typing stops if no field is
+    /// active, e.g. after `Enter` moved on from a single-line field.
+    ///
+    /// Returns the outcome of the last char that wasn't ignored, e.g. [`FormEvent::Submitted`] if a
+    /// final newline submitted the form, or [`FormEvent::Ignored`] if all of `text` was.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tui_form_widget::{Form, FormSelection};
+    /// let mut form = Form::from(vec!["Name"]);
+    /// form.select(FormSelection::Active(0));
+    /// form.type_str("Ferris");
+    /// assert_eq!(form.status()[0].value(), "Ferris");
+    /// ```
+    pub fn type_str(&mut self, text: &str) -> FormEvent {
+        self.recorded(
+            || RecordedInput::TypeStr(text.to_string()),
+            |form| {
+                let mut event = FormEvent::Ignored;
+                for ch in text.chars() {
+                    if !matches!(form.selected, FormSelection::Active(_)) {
+                        break;
+                    }
+                    let key = match ch {
+                        '\n' => KeyCode::Enter,
+                        ch => KeyCode::Char(ch),
+                    };
+                    match form.input(key) {
+                        FormEvent::Ignored => {}
+                        handled => event = handled,
+                    }
+                }
+                event
+            },
+        )
+    }
+
+    /// Append a char to the active field (if one is)
+    pub fn append_selection(&mut self, ch: char) {
+        self.recorded(
+            || RecordedInput::Append(ch),
+            |form| {
+                form.changed();
+                match form.selected() {
+                    FormSelection::NoSelection => {}
+                    FormSelection::Hovered(_) => {}
+                    FormSelection::Active(i) => form.append_field(ch, *i),
+                }
+            },
+        )
+    }
+
+    /// Remove a char frome the active field (if one is)
+    pub fn pop_selection(&mut self) {
+        self.recorded(
+            || RecordedInput::Pop,
+            |form| {
+                form.changed();
+                match form.selected() {
+                    FormSelection::NoSelection => {}
+                    FormSelection::Hovered(_) => {}
+                    FormSelection::Active(i) => form.pop_field(*i),
+                }
+            },
+        )
+    }
+
+    /// Empty the active field (if one is)
+    pub fn clear_selection(&mut self) {
+        self.recorded(
+            || RecordedInput::ClearSelection,
+            |form| {
+                form.changed();
+                if let FormSelection::Active(i) = form.selected {
+                    form.clear_field(i);
+                }
+            },
+        )
+    }
+
+    /// Clears the values of all fields and returns the form to its initial unsubmitted state, with
+    /// the [initial focus](Form::initial_focus) selected.
+    /// With the `zeroize` feature, the memory of secret fields is zeroized.
+    ///
+    /// Custom fields are cleared through [`FieldWidget::set_value`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tui_form_widget::{FieldDef, Form, FormSelection, Tags};
+    /// let mut form = Form::from(vec![FieldDef::tags("Labels", Tags::new(["a", "b"]))]);
+    /// form.reset();
+    /// assert_eq!(form.value("Labels"), Some(""));
+    ///
+    /// form.select(FormSelection::Active(0));
+    /// form.type_str("c,");
+    /// assert_eq!(form.value("Labels"), Some("c"));
+    /// ```
+    pub fn reset(&mut self) {
+        self.recorded(|| RecordedInput::Reset, Self::clear_fields)
+    }
+
+    fn clear_fields(&mut self) {
+        self.changed();
+        self.select(FormSelection::NoSelection);
+        for fb in &mut self.fields {
+            fb.set_value(String::new());
+            fb.validity.take();
+            fb.warned.take();
+            fb.checked = false;
+            fb.touched = false;
+        }
+        self.value_before_active = None;
+        self.suggestions.clear();
+        self.history_index = None;
+        self.submitted = false;
+        self.select(self.initial_selection());
+    }
+
+    /// Activate the first field, so the user can start typing right away.
+    pub fn focus_first(&mut self) {
+        if !self.fields.is_empty() {
+            self.select(FormSelection::Active(0));
+        }
+    }
+
+    /// Activate `field`, so the user can start typing in it right away.
+    ///
+    /// Returns [`Error::UnknownField`] if there's no such field.
+    pub fn focus<'a>(&mut self, field: impl Into<FieldRef<'a, K>>) -> Result<(), Error> {
+        let i = self.field_index(field.into()).ok_or(Error::UnknownField)?;
+        self.select(FormSelection::Active(i));
+        Ok(())
+    }
+
+    /// Insert a field defined by `field` at `index`, shifting the fields after it. The field is
+    /// added at the end if `index` is past it. The selection follows the field it's on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tui_form_widget::{FieldDef, Form, FormSelection};
+    /// let mut form = Form::from(vec!["Street", "Country"]);
+    /// form.select(FormSelection::Hovered(1));
+    /// form.insert_field(1, FieldDef::text("City"));
+    /// assert_eq!(form.status()[1].name(), "City");
+    /// assert_eq!(form.selected(), &FormSelection::Hovered(2));
+    /// ```
+    pub fn insert_field(&mut self, index: usize, field: FieldDef<K>) {
+        self.changed();
+        let index = index.min(self.fields.len());
+        let name = field.0.name.clone();
+        self.fields.insert(index, field.0);
+        self.keys.insert(index, field.1);
+        self.invalidate_links(&name);
+        self.remap_selection(|i| if i >= index { i + 1 } else { i });
+    }
+
+    /// Remove `field` from the form, returning its value. If the field is selected, the selection
+    /// is cleared, otherwise it follows the field it's on.
+    ///
+    /// Returns [`Error::UnknownField`] if there's no such field.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tui_form_widget::{Form, FormSelection};
+    /// let mut form = Form::from(vec![("Name", "Ferris"), ("Nickname", "Crab")]);
+    /// form.select(FormSelection::Active(1));
+    /// assert_eq!(form.remove_field("Name").unwrap(), "Ferris");
+    /// assert_eq!(form.selected(), &FormSelection::Active(0));
+    /// ```
+    pub fn remove_field<'a>(&mut self, field: impl Into<FieldRef<'a, K>>) -> Result<String, Error> {
+        let index = self.field_index(field.into()).ok_or(Error::UnknownField)?;
+        if let FormSelection::Hovered(i) | FormSelection::Active(i) = self.selected {
+            if i == index {
+                self.select(FormSelection::NoSelection);
             }
         }
+        self.changed();
+        let mut fb = self.fields.remove(index);
+        self.keys.remove(index);
+        self.invalidate_links(&fb.name);
+        self.remap_selection(|i| if i > index { i - 1 } else { i });
+        Ok(fb.val.take())
+    }
+
+    /// Move `field` to index `to`, shifting the fields in between. Fields keep their values,
+    /// validators and other settings, and the selection follows the field it's on.
+    ///
+    /// Returns [`Error::UnknownField`] if there's no such field or `to` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tui_form_widget::{Form, FormSelection};
+    /// let mut form = Form::from(vec![("A", "1"), ("B", "2"), ("C", "3")]);
+    /// form.select(FormSelection::Hovered(0));
+    /// form.move_field("A", 2).unwrap();
+    /// let names: Vec<_> = form.status().iter().map(|f| f.name().to_string()).collect();
+    /// assert_eq!(names, ["B", "C", "A"]);
+    /// assert_eq!(form.selected(), &FormSelection::Hovered(2));
+    /// ```
+    pub fn move_field<'a>(
+        &mut self,
+        field: impl Into<FieldRef<'a, K>>,
+        to: usize,
+    ) -> Result<(), Error> {
+        let from = self.field_index(field.into()).ok_or(Error::UnknownField)?;
+        if to >= self.fields.len() {
+            return Err(Error::UnknownField);
+        }
+        self.changed();
+        let fb = self.fields.remove(from);
+        self.fields.insert(to, fb);
+        let key = self.keys.remove(from);
+        self.keys.insert(to, key);
+        self.remap_selection(|i| match i {
+            i if i == from => to,
+            i if from < i && i <= to => i - 1,
+            i if to <= i && i < from => i + 1,
+            i => i,
+        });
+        Ok(())
+    }
+
+    /// Updates the selection after fields were added, removed or moved, with `moved` mapping the
+    /// old index of the selected field to its new one.
+    fn remap_selection(&mut self, moved: impl Fn(usize) -> usize) {
+        self.selected = match self.selected {
+            FormSelection::NoSelection => FormSelection::NoSelection,
+            FormSelection::Hovered(i) => FormSelection::Hovered(moved(i)),
+            FormSelection::Active(i) => FormSelection::Active(moved(i)),
+        };
+    }
+
+    /// Marks the cached validity of fields linked to the field named `name` as out of date, e.g.
+    /// after it was added or removed.
+    fn invalidate_links(&mut self, name: &str) {
+        for fb in &mut self.fields {
+            if matches!(&fb.link, Some((link, _)) if link == name) {
+                fb.validity.take();
+            }
+        }
+    }
+
+    /// Returns the selection for [`Form::initial_focus`].
+    fn initial_selection(&self) -> FormSelection {
+        match self.initial_focus {
+            _ if self.fields.is_empty() => FormSelection::NoSelection,
+            InitialFocus::NoSelection => FormSelection::NoSelection,
+            InitialFocus::Hovered => FormSelection::Hovered(0),
+            InitialFocus::Active => FormSelection::Active(0),
+        }
+    }
+
+    /// De(select / activate) current field
+    pub fn deselect(&mut self) {
+        self.select(FormSelection::NoSelection)
+    }
+
+    /// Move to next field. Retains previous hovered or activated state.
+    ///
+    /// Returns [`FormEvent::ReachedEnd`] if already on the last field and wrapping is disabled.
+    pub fn next_field(&mut self) -> FormEvent {
+        let Some(last) = self.fields.len().checked_sub(1) else {
+            return FormEvent::Ignored;
+        };
+        let next = |i: usize| match self.wrap {
+            WrapBehavior::Wrap => Some((i + 1).rem_euclid(self.fields.len())),
+            WrapBehavior::Stop => (i < last).then_some(i + 1),
+        };
+
+        let selected = match self.selected {
+            FormSelection::NoSelection => FormSelection::Hovered(0),
+            FormSelection::Hovered(i) => match next(i) {
+                Some(i) => FormSelection::Hovered(i),
+                None => return FormEvent::ReachedEnd,
+            },
+            FormSelection::Active(i) => match next(i) {
+                Some(i) => FormSelection::Active(i),
+                None => return FormEvent::ReachedEnd,
+            },
+        };
+        self.select(selected);
+        FormEvent::Handled
+    }
+
+    /// Move to previous field. Retains previous hovered or activated state.
+    ///
+    /// Returns [`FormEvent::ReachedStart`] if already on the first field and wrapping is disabled.
+    pub fn prev_field(&mut self) -> FormEvent {
+        let Some(last) = self.fields.len().checked_sub(1) else {
+            return FormEvent::Ignored;
+        };
+        let prev = |i: usize| match self.wrap {
+            WrapBehavior::Wrap => Some(if i == 0 { last } else { i - 1 }),
+            WrapBehavior::Stop => i.checked_sub(1),
+        };
+
+        let selected = match self.selected {
+            FormSelection::NoSelection => FormSelection::Hovered(0),
+            FormSelection::Hovered(i) => match prev(i) {
+                Some(i) => FormSelection::Hovered(i),
+                None => return FormEvent::ReachedStart,
+            },
+            FormSelection::Active(i) => match prev(i) {
+                Some(i) => FormSelection::Active(i),
+                None => return FormEvent::ReachedStart,
+            },
+        };
+        self.select(selected);
+        FormEvent::Handled
+    }
+
+    /// Set whether navigating past the first/last field wraps around. Defaults to
+    /// [`WrapBehavior::Wrap`].
+    pub fn wrap_behavior(&mut self, wrap: WrapBehavior) {
+        self.wrap = wrap;
+    }
+
+    /// Set what's selected when the form is opened or [reset](Form::reset), so dialogs can open
+    /// ready for typing. The selection is applied right away. Defaults to
+    /// [`InitialFocus::NoSelection`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use tui_form_widget::{Form, FormSelection, InitialFocus};
+    /// let mut form = Form::from(vec!["Name", "Email"]);
+    /// form.initial_focus(InitialFocus::Active);
+    /// assert_eq!(form.selected(), &FormSelection::Active(0));
+    ///
+    /// form.focus("Email").unwrap();
+    /// form.reset();
+    /// assert_eq!(form.selected(), &FormSelection::Active(0));
+    /// ```
+    pub fn initial_focus(&mut self, focus: InitialFocus) {
+        self.initial_focus = focus;
+        self.select(self.initial_selection());
+    }
+
+    /// Set whether moving on from the last field while it's active, with `Enter` by default,
+    /// submits the form instead of moving to the next field. [`Form::input`] then returns
+    /// [`FormEvent::Submitted`] or [`FormEvent::SubmitFailed`] depending on whether every field is
+    /// valid. Defaults to `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crossterm::event::KeyCode;
+    /// # use tui_form_widget::{Form, FormEvent};
+    /// let mut form = Form::from(vec!["Name"]);
+    /// form.submit_on_enter(true);
+    /// form.focus_first();
+    /// assert_eq!(form.input(KeyCode::Enter), FormEvent::SubmitFailed);
+    /// form.type_str("Ferris");
+    /// assert_eq!(form.input(KeyCode::Enter), FormEvent::Submitted);
+    /// ```
+    pub fn submit_on_enter(&mut self, submit: bool) {
+        self.submit_on_enter = submit;
+    }
+
+    /// Set how `Esc` is handled by [`Form::input`]. Defaults to [`EscBehavior::Demote`].
+    pub fn esc_behavior(&mut self, esc: EscBehavior) {
+        self.esc = esc;
+    }
+
+    /// Set the key bindings used by [`Form::input`].
+    pub fn keymap(&mut self, keymap: KeyMap) {
+        self.changed();
+        self.keymap = keymap;
+    }
+
+    /// Show or hide the help overlay listing the form's key bindings.
+    pub fn show_help(&mut self, show: bool) {
+        self.changed();
+        self.show_help = show;
+    }
+
+    /// Show or hide the [tooltip](FieldDef::tooltip) of the hovered field. It's hidden again when
+    /// the selection changes or the next key is pressed.
+    pub fn show_tooltip(&mut self, show: bool) {
+        self.changed();
+        self.show_tooltip = show;
+    }
+
+    /// Show or hide the hint bar listing bindings for the current selection under the form.
+    pub fn show_hints(&mut self, show: bool) {
+        self.changed();
+        self.show_hints = show;
+    }
+
+    /// Set whether control chars in field values, e.g. from pasted text containing ANSI escape
+    /// sequences, are rendered as visible placeholders like `␛` instead of being written to the
+    /// terminal as is. Values themselves are never changed. Defaults to `true`.
+    pub fn sanitize_control_chars(&mut self, sanitize: bool) {
+        self.changed();
+        self.sanitize = sanitize;
+    }
+
+    /// Set the provider of the labels displayed for field names and built-in strings, see
+    /// [`LabelProvider`].
+    pub fn labels(&mut self, labels: impl LabelProvider + 'static) {
+        self.changed();
+        self.labels = Some(Arc::new(labels));
+    }
+
+    /// Set extra `(key, description)` pairs shown in the hint bar while no field is active, e.g.
+    /// for application level bindings like submitting the form.
+    pub fn hints(&mut self, hints: &[(&str, &str)]) {
+        self.changed();
+        self.hints = hints
+            .iter()
+            .map(|(key, desc)| (key.to_string(), desc.to_string()))
+            .collect();
+    }
+
+    /// Call `callback` with the previous and new selection whenever the selection changes, whether
+    /// it moves between fields or a field is activated or left, e.g. to update a preview of the
+    /// focused field.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::sync::{Arc, Mutex};
+    /// # use crossterm::event::KeyCode;
+    /// # use tui_form_widget::{Form, FormSelection};
+    /// let focused = Arc::new(Mutex::new(FormSelection::NoSelection));
+    /// let mut form = Form::from(vec!["Name", "Email"]);
+    /// let f = focused.clone();
+    /// form.on_focus_change(move |_, to| *f.lock().unwrap() = to.clone());
+    ///
+    /// form.input(KeyCode::Char('j'));
+    /// form.input(KeyCode::Char('j'));
+    /// assert_eq!(*focused.lock().unwrap(), FormSelection::Hovered(1));
+    /// ```
+    pub fn on_focus_change(
+        &mut self,
+        callback: impl Fn(&FormSelection, &FormSelection) + Send + Sync + 'static,
+    ) {
+        self.on_focus_change = Some(Arc::new(callback));
+    }
+
+    /// Call `callback` with the index and area of each field drawn whenever they change when the
+    /// form is rendered, e.g. because the terminal was resized, the form scrolled or a field grew.
+    /// It's called while the form is rendered, so anything positioned relative to a field, like a
+    /// popup or the terminal cursor, can be moved in the same frame. See also
+    /// [`field_area`](Self::field_area).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::sync::{Arc, Mutex};
+    /// # use ratatui::prelude::*;
+    /// # use tui_form_widget::{test_utils, Form};
+    /// let areas = Arc::new(Mutex::new(Vec::new()));
+    /// let mut form = Form::from(vec!["Name", "Email"]);
+    /// let a = areas.clone();
+    /// form.on_layout_change(move |layout| *a.lock().unwrap() = layout.to_vec());
+    ///
+    /// test_utils::render(&form, 20, 4);
+    /// assert_eq!(*areas.lock().unwrap(), [(0, Rect::new(0, 0, 20, 3)), (1, Rect::new(0, 3, 20, 1))]);
+    /// test_utils::render(&form, 30, 6);
+    /// assert_eq!(areas.lock().unwrap()[1], (1, Rect::new(0, 3, 30, 3)));
+    /// ```
+    pub fn on_layout_change(
+        &mut self,
+        callback: impl Fn(&[(usize, Rect)]) + Send + Sync + 'static,
+    ) {
+        self.on_layout_change = Some(Arc::new(callback));
+    }
+
+    /// Set how the options of an active select field are displayed. Defaults to
+    /// [`SelectDisplay::Popup`].
+    pub fn select_display(&mut self, display: SelectDisplay) {
+        self.changed();
+        self.select_display = display;
+    }
+
+    /// Set where field labels are aligned on their border. Fields can override this with
+    /// [`FieldDef::title_alignment`]. Defaults to [`Alignment::Left`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::prelude::*;
+    /// # use tui_form_widget::{test_utils, Form};
+    /// let mut form = Form::from(vec!["Host"]);
+    /// form.title_alignment(Alignment::Center);
+    /// form.title_position(ratatui::widgets::block::Position::Bottom);
+    /// let buf = test_utils::render(&form, 12, 3);
+    /// assert_eq!(test_utils::lines(&buf)[2], "╰───Host───╯");
+    /// ```
+    pub fn title_alignment(&mut self, alignment: Alignment) {
+        self.changed();
+        self.title_alignment = alignment;
+    }
+
+    /// Set which border field labels are drawn on. Errors and warnings are shown on the other
+    /// border. Fields can override this with [`FieldDef::title_position`]. Defaults to
+    /// [`Position::Top`].
+    pub fn title_position(&mut self, position: Position) {
+        self.changed();
+        self.title_position = position;
+    }
+
+    /// Set the type of border drawn around fields, popups and the help overlay, replacing any set
+    /// per state with [`border_type_for`](Self::border_type_for). Defaults to
+    /// [`BorderType::Rounded`].
+    pub fn border_type(&mut self, border_type: BorderType) {
+        self.changed();
+        self.border_type = border_type;
+        for (t, _) in &mut self.field_borders {
+            *t = border_type;
+        }
+    }
+
+    /// Set the type of border drawn around fields in `state`, e.g. thick borders only for the
+    /// active field.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::widgets::BorderType;
+    /// # use tui_form_widget::{test_utils, FieldState, Form};
+    /// let mut form = Form::from(vec!["Host", "Port"]);
+    /// form.border_type(BorderType::Plain);
+    /// form.border_type_for(FieldState::Active, BorderType::Thick);
+    /// form.focus_first();
+    /// let buf = test_utils::render(&form, 8, 6);
+    /// assert_eq!(test_utils::lines(&buf)[0], "┏Host━━┓");
+    /// assert_eq!(test_utils::lines(&buf)[3], "┌Port──┐");
+    /// ```
+    pub fn border_type_for(&mut self, state: FieldState, border_type: BorderType) {
+        self.changed();
+        self.field_borders[state as usize].0 = border_type;
+    }
+
+    /// Set which borders are drawn around fields, replacing any set per state with
+    /// [`borders_for`](Self::borders_for). Labels and problems are drawn where the top and bottom
+    /// borders would be even if those aren't drawn. Defaults to [`Borders::ALL`].
+    pub fn borders(&mut self, borders: Borders) {
+        self.changed();
+        for (_, b) in &mut self.field_borders {
+            *b = borders;
+        }
+    }
+
+    /// Set which borders are drawn around fields in `state`, e.g. only a bottom border for fields
+    /// that aren't selected.
+    pub fn borders_for(&mut self, state: FieldState, borders: Borders) {
+        self.changed();
+        self.field_borders[state as usize].1 = borders;
+    }
+
+    /// Set the space between the borders of fields and their content, so values don't sit flush
+    /// against the border. Vertical padding makes fields taller. Defaults to [`Padding::zero`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::widgets::Padding;
+    /// # use tui_form_widget::{test_utils, Form};
+    /// let mut form = Form::from(vec![("Host", "localhost")]);
+    /// form.padding(Padding::horizontal(1));
+    /// let buf = test_utils::render(&form, 14, 3);
+    /// assert_eq!(test_utils::lines(&buf)[1], "│ localhost  │");
+    /// ```
+    pub fn padding(&mut self, padding: Padding) {
+        self.changed();
+        self.padding = padding;
+    }
+
+    /// Set when fields are validated. Defaults to [`ValidationMode::OnSubmit`].
+    pub fn validation_mode(&mut self, mode: ValidationMode) {
+        self.changed();
+        self.validation_mode = mode;
+    }
+
+    /// Set how field values are cleaned up when the form is submitted. Fields can override this
+    /// with [`FieldDef::normalize`]. Secret, [textarea](FieldDef::textarea) and custom fields, like
+    /// [string lists](FieldDef::string_list), are left as they are unless they do, as cleaning up
+    /// their values as a whole would join their lines. Defaults to [`Normalize::NONE`].
+    pub fn normalize(&mut self, normalize: Normalize) {
+        self.normalize = normalize;
     }
 
     /// Set whether the Form has been submitted
     pub fn submitted(&mut self, submitted: bool) {
+        self.changed();
         self.submitted = submitted;
     }
 
+    /// Set style applied over the whole form while it doesn't have the focus, see
+    /// [`Focusable`](crate::Focusable).
+    pub fn unfocused_style(&mut self, style: Style) {
+        self.changed();
+        self.unfocused_style = style;
+    }
+
     /// Set style for the active field.
     pub fn active_field_style(&mut self, style: Style) {
+        self.changed();
         self.active_field_style = style;
     }
 
     /// Set style for any invalid fields.
     pub fn invalid_field_style(&mut self, style: Style) {
+        self.changed();
         self.invalid_field_style = style;
     }
 
+    /// Set style for chars not allowed in a field, see
+    /// [`FieldDef::allowed_chars`](crate::FieldDef::allowed_chars).
+    pub fn invalid_char_style(&mut self, style: Style) {
+        self.changed();
+        self.invalid_char_style = style;
+    }
+
+    /// Set style for any fields with warnings.
+    pub fn warning_field_style(&mut self, style: Style) {
+        self.changed();
+        self.warning_field_style = style;
+    }
+
     /// Set the style for the hovered field.
     pub fn hovered_field_style(&mut self, style: Style) {
+        self.changed();
         self.hovered_field_style = style;
     }
 
     /// Set style for a valid, unselected field.
     pub fn default_field_style(&mut self, style: Style) {
+        self.changed();
         self.default_field_style = style;
     }
 }
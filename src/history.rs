@@ -0,0 +1,27 @@
+use std::collections::BTreeMap;
+
+/// Previously submitted values of a [`Form`](crate::Form)'s fields, keyed by field name, oldest
+/// first.
+///
+/// Only fields with history enabled via [`FieldDef::history`](crate::FieldDef::history) are
+/// recorded. With the `serde` feature enabled this can be saved and restored between sessions
+/// using [`Form::history`](crate::Form::history) and
+/// [`Form::load_history`](crate::Form::load_history).
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FormHistory {
+    /// Submitted values for each field name.
+    pub fields: BTreeMap<String, Vec<String>>,
+}
+
+/// Pushes `val` onto `history`, skipping empty values and repeats of the latest entry and dropping
+/// the oldest entries beyond `limit`.
+pub(crate) fn record(history: &mut Vec<String>, val: &str, limit: usize) {
+    if val.is_empty() || history.last().is_some_and(|last| last == val) {
+        return;
+    }
+    history.push(val.to_string());
+    if history.len() > limit {
+        history.drain(..history.len() - limit);
+    }
+}
@@ -0,0 +1,35 @@
+use std::fmt;
+
+/// Identifies the fields of a [`Form`](crate::Form) by a type of the application's own, like an
+/// enum, instead of by index or name, so lookups are checked at compile time.
+///
+/// Forms are keyed by `String` by default, using each field's name. Give fields keys of another
+/// type with [`FieldDef::key`](crate::FieldDef::key), and the form built from them accepts those
+/// keys anywhere a [`FieldRef`](crate::FieldRef) is, and tags its
+/// [submitted fields](crate::SubmittedField::key) with them.
+///
+/// # Example
+///
+/// ```
+/// # use tui_form_widget::{FieldDef, FieldKey, Form};
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// enum FieldId {
+///     Username,
+///     Password,
+/// }
+///
+/// impl FieldKey for FieldId {}
+///
+/// let mut form = Form::from(vec![
+///     FieldDef::text("Username").value("ferris").key(FieldId::Username),
+///     FieldDef::text("Password").secret().key(FieldId::Password),
+/// ]);
+/// assert_eq!(form.value(FieldId::Username), Some("ferris"));
+/// form.focus(FieldId::Password).unwrap();
+///
+/// let submitted = form.into_values();
+/// assert_eq!(*submitted[0].key(), FieldId::Username);
+/// ```
+pub trait FieldKey: Clone + PartialEq + fmt::Debug + Send + Sync + 'static {}
+
+impl FieldKey for String {}
@@ -0,0 +1,186 @@
+//! A field editing a list of key/value pairs, like HTTP headers.
+
+use std::fmt;
+
+use crossterm::event::KeyCode;
+use ratatui::prelude::*;
+
+use crate::text::TextBuffer;
+use crate::value;
+use crate::widget::{write_text, TextStyle};
+use crate::{FieldWidget, FormEvent};
+
+/// Edits a list of key/value pairs, one row per pair, added to a form with
+/// [`FieldDef::key_values`](crate::FieldDef::key_values).
+///
+/// While the field is active:
+///
+/// - `Tab`/`Shift+Tab` move between keys and values, and typing `=` in a key moves to its value.
+/// - `Up`/`Down` move between rows.
+/// - `Insert` adds a row below the current one, and `Delete` removes the current row.
+///
+/// Other keys edit the key or value under the cursor, except `Enter` and `Esc`, which are handled
+/// by the form as usual. The field's value has one `key=value` line per pair with a key, see
+/// [`Field::pairs`](crate::Field::pairs).
+#[derive(Clone)]
+pub struct KeyValues {
+    rows: Vec<(TextBuffer, TextBuffer)>,
+    row: usize,
+    on_value: bool,
+}
+
+impl KeyValues {
+    /// Create an editor for `pairs`, with an empty row if there are none. Since keys and values
+    /// are single lines and keys end at the first `=`, newlines are replaced with spaces and `=`
+    /// is removed from keys.
+    pub fn new<K: AsRef<str>, V: AsRef<str>>(pairs: impl IntoIterator<Item = (K, V)>) -> Self {
+        let mut rows = pairs
+            .into_iter()
+            .map(|(k, v)| {
+                let key = k.as_ref().replace('=', "").replace('\n', " ");
+                let value = v.as_ref().replace('\n', " ");
+                (TextBuffer::new(&key), TextBuffer::new(&value))
+            })
+            .collect::<Vec<_>>();
+        if rows.is_empty() {
+            rows.push(Default::default());
+        }
+        Self {
+            rows,
+            row: 0,
+            on_value: false,
+        }
+    }
+
+    /// The pairs being edited, skipping rows without a key.
+    pub fn pairs(&self) -> Vec<(String, String)> {
+        self.rows
+            .iter()
+            .filter(|(k, _)| !k.is_empty())
+            .map(|(k, v)| (k.as_str().to_string(), v.as_str().to_string()))
+            .collect()
+    }
+
+    fn cell(&mut self) -> &mut TextBuffer {
+        let (key, value) = &mut self.rows[self.row];
+        if self.on_value {
+            value
+        } else {
+            key
+        }
+    }
+
+    fn next_cell(&mut self) -> FormEvent {
+        if !self.on_value {
+            self.on_value = true;
+        } else if self.row + 1 < self.rows.len() {
+            (self.row, self.on_value) = (self.row + 1, false);
+        } else {
+            return FormEvent::Ignored;
+        }
+        FormEvent::Handled
+    }
+
+    fn prev_cell(&mut self) -> FormEvent {
+        if self.on_value {
+            self.on_value = false;
+        } else if self.row > 0 {
+            (self.row, self.on_value) = (self.row - 1, true);
+        } else {
+            return FormEvent::Ignored;
+        }
+        FormEvent::Handled
+    }
+}
+
+impl Default for KeyValues {
+    fn default() -> Self {
+        Self::new(Vec::<(String, String)>::new())
+    }
+}
+
+impl fmt::Debug for KeyValues {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeyValues")
+            .field("pairs", &self.pairs())
+            .field("row", &self.row)
+            .field("on_value", &self.on_value)
+            .finish()
+    }
+}
+
+impl FieldWidget for KeyValues {
+    fn handle_input(&mut self, key: KeyCode) -> FormEvent {
+        match key {
+            KeyCode::Tab => return self.next_cell(),
+            KeyCode::BackTab => return self.prev_cell(),
+            KeyCode::Char('=') if !self.on_value => self.on_value = true,
+            KeyCode::Up if self.row > 0 => self.row -= 1,
+            KeyCode::Down if self.row + 1 < self.rows.len() => self.row += 1,
+            KeyCode::Insert => {
+                self.row += 1;
+                self.rows.insert(self.row, Default::default());
+                self.on_value = false;
+            }
+            KeyCode::Delete => {
+                self.rows.remove(self.row);
+                if self.rows.is_empty() {
+                    self.rows.push(Default::default());
+                }
+                self.row = self.row.min(self.rows.len() - 1);
+                self.on_value = false;
+            }
+            KeyCode::Char(ch) => self.cell().insert(ch),
+            KeyCode::Backspace => _ = self.cell().delete_backward(),
+            KeyCode::Left => _ = self.cell().move_left(),
+            KeyCode::Right => _ = self.cell().move_right(),
+            KeyCode::Home => self.cell().move_home(),
+            KeyCode::End => self.cell().move_end(),
+            _ => return FormEvent::Ignored,
+        }
+        FormEvent::Handled
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, active: bool) {
+        let key_width = area.width.saturating_sub(3) / 2;
+        let value_x = area.x + key_width + 3;
+        let style = TextStyle {
+            masked: false,
+            sanitize: true,
+            selected: false,
+            allowed: None,
+        };
+        for (i, ((key, value), y)) in self.rows.iter().zip(area.top()..area.bottom()).enumerate() {
+            let key_area = Rect::new(area.x, y, key_width, 1);
+            let value_area = Rect::new(value_x, y, area.right().saturating_sub(value_x), 1);
+            buf.set_stringn(key_area.right(), y, " = ", 3, Style::default().dim());
+
+            let current = active && i == self.row;
+            for (text, area, on_value) in [(key, key_area, false), (value, value_area, true)] {
+                if area.width == 0 {
+                    continue;
+                }
+                let cursor = write_text(buf, area, text, "", style);
+                if let (Some((x, y)), true) = (cursor, current && self.on_value == on_value) {
+                    buf.get_mut(x, y).set_style(Style::default().reversed());
+                }
+            }
+        }
+    }
+
+    fn value(&self) -> String {
+        self.pairs()
+            .iter()
+            .map(|(k, v)| format!("{k}={v}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn set_value(&mut self, value: &str) {
+        *self = Self::new(value::pairs(value));
+    }
+
+    fn height(&self) -> u16 {
+        self.rows.len() as u16
+    }
+}
@@ -0,0 +1,271 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+
+/// Actions that keys can be bound to in a [`KeyMap`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Action {
+    /// Move to the next field.
+    NextField,
+    /// Move to the previous field.
+    PrevField,
+    /// Start editing the hovered field.
+    Activate,
+    /// Leave the current field, see [`EscBehavior`](crate::EscBehavior).
+    Escape,
+    /// Remove the char before the cursor in the active field.
+    DeleteBackward,
+    /// Remove the char after the cursor in the active field.
+    DeleteForward,
+    /// Empty the active field.
+    ClearField,
+    /// Move the cursor one char left.
+    MoveLeft,
+    /// Move the cursor one char right.
+    MoveRight,
+    /// Move the cursor to the start of the line.
+    MoveHome,
+    /// Move the cursor to the end of the line.
+    MoveEnd,
+    /// Move the cursor to the start of the previous word.
+    MoveWordLeft,
+    /// Move the cursor to the end of the next word.
+    MoveWordRight,
+    /// Select the whole value of the active field, so typing replaces it and deleting clears it.
+    SelectAll,
+    /// Move the hovered field up, reordering the form. Not bound by default.
+    MoveFieldUp,
+    /// Move the hovered field down, reordering the form. Not bound by default.
+    MoveFieldDown,
+    /// Increase the value of an active number field by its step.
+    Increment,
+    /// Decrease the value of an active number field by its step.
+    Decrement,
+    /// Increase the value of an active number field by its large step.
+    IncrementLarge,
+    /// Decrease the value of an active number field by its large step.
+    DecrementLarge,
+    /// Open or close the file browser of an active [path](crate::FieldDef::path) field.
+    Browse,
+    /// Show or hide the help overlay.
+    ToggleHelp,
+}
+
+impl Action {
+    /// Short, human readable description of the action.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Action::NextField => "Next field",
+            Action::PrevField => "Previous field",
+            Action::Activate => "Edit field",
+            Action::Escape => "Leave field",
+            Action::DeleteBackward => "Delete char",
+            Action::DeleteForward => "Delete next char",
+            Action::ClearField => "Clear field",
+            Action::MoveLeft => "Cursor left",
+            Action::MoveRight => "Cursor right",
+            Action::MoveHome => "Cursor to line start",
+            Action::MoveEnd => "Cursor to line end",
+            Action::MoveWordLeft => "Cursor to previous word",
+            Action::MoveWordRight => "Cursor to next word",
+            Action::SelectAll => "Select all",
+            Action::MoveFieldUp => "Move field up",
+            Action::MoveFieldDown => "Move field down",
+            Action::Increment => "Increase number",
+            Action::Decrement => "Decrease number",
+            Action::IncrementLarge => "Increase number a lot",
+            Action::DecrementLarge => "Decrease number a lot",
+            Action::Browse => "Browse files",
+            Action::ToggleHelp => "Toggle help",
+        }
+    }
+
+    /// Terse label used by the hint bar. Actions sharing a label are grouped together.
+    pub(crate) fn hint(&self) -> &'static str {
+        match self {
+            Action::NextField | Action::PrevField => "move",
+            Action::Activate => "edit",
+            Action::Escape => "back",
+            Action::DeleteBackward | Action::DeleteForward | Action::ClearField => "delete",
+            Action::MoveLeft
+            | Action::MoveRight
+            | Action::MoveHome
+            | Action::MoveEnd
+            | Action::MoveWordLeft
+            | Action::MoveWordRight => "cursor",
+            Action::SelectAll => "select all",
+            Action::MoveFieldUp | Action::MoveFieldDown => "reorder",
+            Action::Increment
+            | Action::Decrement
+            | Action::IncrementLarge
+            | Action::DecrementLarge => "adjust",
+            Action::Browse => "browse",
+            Action::ToggleHelp => "help",
+        }
+    }
+}
+
+/// Key bindings used by [`Form::input`](crate::Form::input).
+///
+/// Bindings are split between navigating the form and editing the active field, since any
+/// unbound printable key typed while editing is inserted into the field. Keys pressed with
+/// modifiers, like `Ctrl+Left`, are bound separately as chords and handled by
+/// [`Form::input_event`](crate::Form::input_event).
+#[derive(Clone, Debug)]
+pub struct KeyMap {
+    navigation: Vec<(KeyCode, Action)>,
+    editing: Vec<(KeyCode, Action)>,
+    chords: Vec<(KeyModifiers, KeyCode, Action)>,
+}
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        Self {
+            navigation: vec![
+                (KeyCode::Enter, Action::Activate),
+                (KeyCode::Char('j'), Action::NextField),
+                (KeyCode::Char('k'), Action::PrevField),
+                (KeyCode::Esc, Action::Escape),
+                (KeyCode::Char('?'), Action::ToggleHelp),
+            ],
+            editing: vec![
+                (KeyCode::Enter, Action::NextField),
+                (KeyCode::Backspace, Action::DeleteBackward),
+                (KeyCode::Delete, Action::DeleteForward),
+                (KeyCode::Left, Action::MoveLeft),
+                (KeyCode::Right, Action::MoveRight),
+                (KeyCode::Home, Action::MoveHome),
+                (KeyCode::End, Action::MoveEnd),
+                (KeyCode::Up, Action::Increment),
+                (KeyCode::Down, Action::Decrement),
+                (KeyCode::PageUp, Action::IncrementLarge),
+                (KeyCode::PageDown, Action::DecrementLarge),
+                (KeyCode::Tab, Action::Browse),
+                (KeyCode::Esc, Action::Escape),
+            ],
+            chords: vec![
+                (KeyModifiers::CONTROL, KeyCode::Left, Action::MoveWordLeft),
+                (KeyModifiers::CONTROL, KeyCode::Right, Action::MoveWordRight),
+                (KeyModifiers::ALT, KeyCode::Char('b'), Action::MoveWordLeft),
+                (KeyModifiers::ALT, KeyCode::Char('f'), Action::MoveWordRight),
+                (KeyModifiers::CONTROL, KeyCode::Char('a'), Action::SelectAll),
+                (
+                    KeyModifiers::CONTROL,
+                    KeyCode::Char('l'),
+                    Action::ClearField,
+                ),
+                (KeyModifiers::CONTROL, KeyCode::Up, Action::Increment),
+                (KeyModifiers::CONTROL, KeyCode::Down, Action::Decrement),
+                (KeyModifiers::SHIFT, KeyCode::Up, Action::IncrementLarge),
+                (KeyModifiers::SHIFT, KeyCode::Down, Action::DecrementLarge),
+            ],
+        }
+    }
+}
+
+impl KeyMap {
+    /// Create a [`KeyMap`] without any bindings.
+    pub fn empty() -> Self {
+        Self {
+            navigation: Vec::new(),
+            editing: Vec::new(),
+            chords: Vec::new(),
+        }
+    }
+
+    /// Bind `key` to `action` while no field is active. Replaces any existing binding for `key`.
+    pub fn bind_navigation(&mut self, key: KeyCode, action: Action) {
+        bind(&mut self.navigation, key, action);
+    }
+
+    /// Bind `key` to `action` while a field is active. Replaces any existing binding for `key`.
+    pub fn bind_editing(&mut self, key: KeyCode, action: Action) {
+        bind(&mut self.editing, key, action);
+    }
+
+    /// Bind `key` pressed with `modifiers` to `action` while a field is active. `Shift` is ignored
+    /// for chars, since it's already reflected in the char typed, but can be bound with other keys,
+    /// e.g. `Shift+Up`. Replaces any existing binding for the chord.
+    pub fn bind_chord(&mut self, modifiers: KeyModifiers, key: KeyCode, action: Action) {
+        let modifiers = match key {
+            KeyCode::Char(_) => modifiers.difference(KeyModifiers::SHIFT),
+            _ => modifiers,
+        };
+        match self
+            .chords
+            .iter_mut()
+            .find(|(m, k, _)| *m == modifiers && *k == key)
+        {
+            Some(binding) => binding.2 = action,
+            None => self.chords.push((modifiers, key, action)),
+        }
+    }
+
+    /// Bindings used while no field is active.
+    pub fn navigation(&self) -> &[(KeyCode, Action)] {
+        &self.navigation
+    }
+
+    /// Bindings used while a field is active.
+    pub fn editing(&self) -> &[(KeyCode, Action)] {
+        &self.editing
+    }
+
+    /// Chords used while a field is active.
+    pub fn chords(&self) -> &[(KeyModifiers, KeyCode, Action)] {
+        &self.chords
+    }
+
+    pub(crate) fn navigation_action(&self, key: KeyCode) -> Option<Action> {
+        lookup(&self.navigation, key)
+    }
+
+    pub(crate) fn editing_action(&self, key: KeyCode) -> Option<Action> {
+        lookup(&self.editing, key)
+    }
+
+    pub(crate) fn chord_action(&self, modifiers: KeyModifiers, key: KeyCode) -> Option<Action> {
+        self.chords
+            .iter()
+            .find(|(m, k, _)| *m == modifiers && *k == key)
+            .map(|(_, _, a)| *a)
+    }
+}
+
+fn bind(bindings: &mut Vec<(KeyCode, Action)>, key: KeyCode, action: Action) {
+    match bindings.iter_mut().find(|(k, _)| *k == key) {
+        Some(binding) => binding.1 = action,
+        None => bindings.push((key, action)),
+    }
+}
+
+fn lookup(bindings: &[(KeyCode, Action)], key: KeyCode) -> Option<Action> {
+    bindings.iter().find(|(k, _)| *k == key).map(|(_, a)| *a)
+}
+
+/// Human readable name of a key, as shown in the help overlay.
+pub(crate) fn key_label(key: KeyCode) -> String {
+    match key {
+        KeyCode::Char(' ') => "Space".to_string(),
+        KeyCode::Char(ch) => ch.to_string(),
+        KeyCode::F(n) => format!("F{n}"),
+        KeyCode::BackTab => "Shift+Tab".to_string(),
+        KeyCode::PageUp => "PgUp".to_string(),
+        KeyCode::PageDown => "PgDn".to_string(),
+        key => format!("{key:?}"),
+    }
+}
+
+/// Human readable name of a chord, e.g. `Ctrl+Left`.
+pub(crate) fn chord_label(modifiers: KeyModifiers, key: KeyCode) -> String {
+    let mut label = String::new();
+    for (modifier, name) in [
+        (KeyModifiers::CONTROL, "Ctrl+"),
+        (KeyModifiers::ALT, "Alt+"),
+        (KeyModifiers::SUPER, "Super+"),
+        (KeyModifiers::SHIFT, "Shift+"),
+    ] {
+        if modifiers.contains(modifier) {
+            label.push_str(name);
+        }
+    }
+    label + &key_label(key)
+}
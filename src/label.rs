@@ -0,0 +1,34 @@
+/// Maps keys to the labels displayed for them, so forms can be localized.
+///
+/// Keys are the names of fields, which are used as their titles, and the English text of the
+/// built-in strings: the `"Form"` title, the `"Help"`, `"Navigation"` and `"Editing"` headings of
+/// the help overlay, action [descriptions](crate::Action::description) and the terse action
+/// labels of the hint bar, like `"move"` or `"edit"`. Keys without a label are displayed as is.
+///
+/// Labels are looked up every time the form is rendered, so a provider reading the current
+/// language from shared state switches language without rebuilding the form.
+///
+/// Closures taking a key and returning its label implement this trait. Providers must be `Send`
+/// and `Sync` so forms can be shared across threads.
+///
+/// # Example
+///
+/// ```
+/// # use tui_form_widget::Form;
+/// let mut form = Form::from(vec!["Name", "Email"]);
+/// form.labels(|key: &str| match key {
+///     "Name" => Some("Nom".to_string()),
+///     "Email" => Some("Courriel".to_string()),
+///     _ => None,
+/// });
+/// ```
+pub trait LabelProvider: Send + Sync {
+    /// Returns the label for `key`, or `None` to display `key` as is.
+    fn label(&self, key: &str) -> Option<String>;
+}
+
+impl<F: Fn(&str) -> Option<String> + Send + Sync> LabelProvider for F {
+    fn label(&self, key: &str) -> Option<String> {
+        self(key)
+    }
+}
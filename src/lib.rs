@@ -1,7 +1,56 @@
 #![doc = include_str!("../README.md")]
 #![warn(missing_docs)]
+#![warn(missing_debug_implementations)]
 
+#[cfg(feature = "bidi")]
+mod bidi;
+mod browser;
+mod custom;
+mod duration;
+mod error;
+mod field;
+mod focus;
 mod form;
+mod history;
+mod key;
+mod key_values;
+mod keymap;
+mod label;
+mod normalize;
+mod record;
+mod secret;
+mod string_list;
+#[cfg(feature = "serde")]
+mod structs;
+mod suggest;
+mod tags;
+pub mod test_utils;
+mod text;
+#[cfg(feature = "tui-input")]
+mod tui_input;
+#[cfg(feature = "tui-textarea")]
+mod tui_textarea;
+mod url;
+mod value;
 mod widget;
 
-pub use form::{Field, Form, FormSelection};
+pub use custom::FieldWidget;
+pub use error::Error;
+pub use field::{FieldDef, SelectDisplay};
+pub use focus::Focusable;
+pub use form::{
+    EscBehavior, Field, FieldRef, Form, FormEvent, FormSelection, InitialFocus, Severity,
+    SubmittedField, ValidationMode, WrapBehavior,
+};
+pub use history::FormHistory;
+pub use key::FieldKey;
+pub use key_values::KeyValues;
+pub use keymap::{Action, KeyMap};
+pub use label::LabelProvider;
+pub use normalize::Normalize;
+pub use record::RecordedInput;
+pub use string_list::StringList;
+pub use suggest::{StaticSuggestions, SuggestionProvider};
+pub use tags::Tags;
+pub use value::FieldValue;
+pub use widget::FieldState;
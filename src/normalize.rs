@@ -0,0 +1,95 @@
+/// Cleanup applied to field values when a [`Form`](crate::Form) is submitted, before they're
+/// validated.
+///
+/// Set it for the whole form with [`Form::normalize`](crate::Form::normalize) or per field with
+/// [`FieldDef::normalize`](crate::FieldDef::normalize).
+///
+/// # Example
+///
+/// ```
+/// # use tui_form_widget::{FieldDef, Form, Normalize};
+/// let mut form = Form::from(vec![FieldDef::text("Email").value("  Ferris@Example.COM ")]);
+/// form.normalize(Normalize::TRIM.lowercase());
+/// assert_eq!(form.submit()[0].value(), "ferris@example.com");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Normalize {
+    /// Remove leading and trailing whitespace.
+    pub trim: bool,
+    /// Replace runs of whitespace, including newlines, with a single space.
+    pub collapse_whitespace: bool,
+    /// Convert the value to lowercase.
+    pub lowercase: bool,
+}
+
+impl Normalize {
+    /// Leave values as they are.
+    pub const NONE: Self = Self {
+        trim: false,
+        collapse_whitespace: false,
+        lowercase: false,
+    };
+
+    /// Only remove leading and trailing whitespace.
+    pub const TRIM: Self = Self {
+        trim: true,
+        ..Self::NONE
+    };
+
+    /// Also replace runs of whitespace with a single space.
+    pub const fn collapse_whitespace(self) -> Self {
+        Self {
+            collapse_whitespace: true,
+            ..self
+        }
+    }
+
+    /// Also convert values to lowercase.
+    pub const fn lowercase(self) -> Self {
+        Self {
+            lowercase: true,
+            ..self
+        }
+    }
+
+    /// Returns the normalized `val`, or `None` if it's already normalized.
+    pub(crate) fn apply(&self, val: &str) -> Option<String> {
+        if *self == Self::NONE {
+            return None;
+        }
+
+        let trimmed = if self.trim { val.trim() } else { val };
+        let mut normalized = String::with_capacity(trimmed.len());
+        let mut in_whitespace = false;
+        for ch in trimmed.chars() {
+            if self.collapse_whitespace && ch.is_whitespace() {
+                if !in_whitespace {
+                    normalized.push(' ');
+                }
+                in_whitespace = true;
+                continue;
+            }
+            in_whitespace = false;
+            if self.lowercase {
+                normalized.extend(ch.to_lowercase());
+            } else {
+                normalized.push(ch);
+            }
+        }
+        (normalized != val).then_some(normalized)
+    }
+
+    /// Like [`Normalize::apply`], but cleans up each line of `val` on its own, e.g. the entries of
+    /// a list.
+    pub(crate) fn apply_lines(&self, val: &str) -> Option<String> {
+        if *self == Self::NONE {
+            return None;
+        }
+        let normalized = val
+            .split('\n')
+            .map(|line| self.apply(line).unwrap_or_else(|| line.to_string()))
+            .collect::<Vec<_>>()
+            .join("\n");
+        (normalized != val).then_some(normalized)
+    }
+}
@@ -0,0 +1,115 @@
+//! Recording input to a form and replaying it.
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::{FieldKey, Form, FormSelection};
+
+/// Input to a [`Form`] captured by [`Form::record`].
+///
+/// Each variant corresponds to a call of the `Form` method it's named after. Only the outermost
+/// call is recorded, e.g. handling a key that moves to the next field records the key but not the
+/// selection change it caused.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum RecordedInput {
+    /// [`Form::input`]
+    Key(KeyCode),
+    /// [`Form::input_event`], for keys pressed with modifiers.
+    Event(KeyEvent),
+    /// [`Form::type_str`]
+    TypeStr(String),
+    /// [`Form::select`], or methods changing the selection like [`Form::next_field`].
+    Select(FormSelection),
+    /// [`Form::append_selection`]
+    Append(char),
+    /// [`Form::pop_selection`]
+    Pop,
+    /// [`Form::clear_selection`]
+    ClearSelection,
+    /// [`Form::set_composition`]
+    SetComposition(String),
+    /// [`Form::commit_composition`]
+    CommitComposition,
+    /// [`Form::validate_field`], with the index of the field.
+    ValidateField(usize),
+    /// [`Form::submit`]
+    Submit,
+    /// [`Form::reset`]
+    Reset,
+}
+
+impl<K: FieldKey> Form<K> {
+    /// Start recording input to the form, discarding any previous recording. Recordings can be
+    /// replayed with [`Form::replay`] to reproduce the state the form ended up in, e.g. to turn a
+    /// bug report into a regression test.
+    ///
+    /// Recordings contain everything typed into the form, including the values of secret fields,
+    /// so take care where they're stored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use crossterm::event::KeyCode;
+    /// # use tui_form_widget::{Form, FormSelection};
+    /// let mut form = Form::from(vec!["Name"]);
+    /// form.record();
+    /// form.input(KeyCode::Enter);
+    /// form.type_str("Ferris");
+    /// let recording = form.stop_recording();
+    ///
+    /// let mut replayed = Form::from(vec!["Name"]);
+    /// replayed.replay(&recording);
+    /// assert_eq!(replayed.selected(), &FormSelection::Active(0));
+    /// assert_eq!(replayed.status()[0].value(), "Ferris");
+    /// ```
+    pub fn record(&mut self) {
+        self.recording = Some(Vec::new());
+    }
+
+    /// Stop recording input, returning what was recorded since [`Form::record`].
+    pub fn stop_recording(&mut self) -> Vec<RecordedInput> {
+        self.recording.take().unwrap_or_default()
+    }
+
+    /// Input recorded so far, empty if the form isn't being recorded.
+    pub fn recording(&self) -> &[RecordedInput] {
+        self.recording.as_deref().unwrap_or_default()
+    }
+
+    /// Feed recorded `inputs` to the form in order.
+    pub fn replay(&mut self, inputs: &[RecordedInput]) {
+        for input in inputs {
+            match input {
+                RecordedInput::Key(key) => _ = self.input(*key),
+                RecordedInput::Event(event) => _ = self.input_event(*event),
+                RecordedInput::TypeStr(text) => _ = self.type_str(text),
+                RecordedInput::Select(selection) => self.select(selection.clone()),
+                RecordedInput::Append(ch) => self.append_selection(*ch),
+                RecordedInput::Pop => self.pop_selection(),
+                RecordedInput::ClearSelection => self.clear_selection(),
+                RecordedInput::SetComposition(text) => self.set_composition(text),
+                RecordedInput::CommitComposition => self.commit_composition(),
+                RecordedInput::ValidateField(i) => _ = self.validate_field(*i),
+                RecordedInput::Submit => _ = self.submit(),
+                RecordedInput::Reset => self.reset(),
+            }
+        }
+    }
+
+    /// Records the input built by `input` if the form is being recorded, then handles it with `f`.
+    /// Input handled by `f` isn't recorded, so only the outermost call is. The input is only built
+    /// while recording, so typed text isn't copied otherwise.
+    pub(crate) fn recorded<R>(
+        &mut self,
+        input: impl FnOnce() -> RecordedInput,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        let Some(mut recording) = self.recording.take() else {
+            return f(self);
+        };
+        recording.push(input());
+        let result = f(self);
+        self.recording = Some(recording);
+        result
+    }
+}
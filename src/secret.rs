@@ -0,0 +1,50 @@
+//! Helpers for editing secret field values without leaving copies of them in memory when the
+//! `zeroize` feature is enabled.
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// A `String` that's zeroized when dropped if the `zeroize` feature is enabled.
+#[derive(Clone, Default)]
+pub(crate) struct SecretString(pub(crate) String);
+
+#[cfg(feature = "zeroize")]
+impl Drop for SecretString {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// Clears `buf`, zeroizing its contents if the `zeroize` feature is enabled.
+pub(crate) fn clear(buf: &mut String) {
+    #[cfg(feature = "zeroize")]
+    buf.zeroize();
+    #[cfg(not(feature = "zeroize"))]
+    buf.clear();
+}
+
+/// Replaces the contents of `buf` with `val`, zeroizing the old contents.
+pub(crate) fn set(buf: &mut String, val: String) {
+    clear(buf);
+    *buf = val;
+}
+
+/// Pushes `ch` onto `buf`. If `buf` has to grow, the old allocation is zeroized rather than left
+/// for the allocator to hand out again.
+pub(crate) fn push(buf: &mut String, ch: char) {
+    if buf.len() + ch.len_utf8() > buf.capacity() {
+        let mut grown = String::with_capacity((buf.capacity() * 2).max(32));
+        grown.push_str(buf);
+        set(buf, grown);
+    }
+    buf.push(ch);
+}
+
+/// Pops the last char from `buf`, zeroizing the bytes it occupied.
+pub(crate) fn pop(buf: &mut String) {
+    let mut shrunk = String::with_capacity(buf.capacity());
+    let mut chars = buf.chars();
+    chars.next_back();
+    shrunk.push_str(chars.as_str());
+    set(buf, shrunk);
+}
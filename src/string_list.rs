@@ -0,0 +1,149 @@
+//! A field editing a list of strings, like a list of hosts.
+
+use std::fmt;
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::prelude::*;
+
+use crate::text::TextBuffer;
+use crate::widget::{write_text, TextStyle};
+use crate::{FieldWidget, FormEvent};
+
+/// Edits a list of strings, one row per entry, added to a form with
+/// [`FieldDef::string_list`](crate::FieldDef::string_list).
+///
+/// While the field is active:
+///
+/// - `Up`/`Down` move between entries.
+/// - `Insert` adds an entry below the current one, and `Delete` removes the current entry.
+/// - `Alt+Up`/`Alt+Down` move the current entry up or down, reordering the list.
+///
+/// Other keys edit the current entry, except `Enter` and `Esc`, which are handled by the form as
+/// usual. The field's value has one line per non-empty entry, see
+/// [`Field::items`](crate::Field::items).
+#[derive(Clone)]
+pub struct StringList {
+    items: Vec<TextBuffer>,
+    current: usize,
+}
+
+impl StringList {
+    /// Create an editor for `items`, with an empty entry if there are none. Since entries are
+    /// single lines, newlines are replaced with spaces.
+    pub fn new<S: AsRef<str>>(items: impl IntoIterator<Item = S>) -> Self {
+        let mut items = items
+            .into_iter()
+            .map(|item| TextBuffer::new(&item.as_ref().replace('\n', " ")))
+            .collect::<Vec<_>>();
+        if items.is_empty() {
+            items.push(TextBuffer::default());
+        }
+        Self { items, current: 0 }
+    }
+
+    /// The entries being edited, skipping empty ones.
+    pub fn items(&self) -> Vec<String> {
+        self.items
+            .iter()
+            .filter(|item| !item.is_empty())
+            .map(|item| item.as_str().to_string())
+            .collect()
+    }
+}
+
+impl Default for StringList {
+    fn default() -> Self {
+        Self::new(Vec::<String>::new())
+    }
+}
+
+impl fmt::Debug for StringList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StringList")
+            .field("items", &self.items())
+            .field("current", &self.current)
+            .finish()
+    }
+}
+
+impl FieldWidget for StringList {
+    fn handle_input(&mut self, key: KeyCode) -> FormEvent {
+        match key {
+            KeyCode::Up if self.current > 0 => self.current -= 1,
+            KeyCode::Down if self.current + 1 < self.items.len() => self.current += 1,
+            KeyCode::Insert => {
+                self.current += 1;
+                self.items.insert(self.current, TextBuffer::default());
+            }
+            KeyCode::Delete => {
+                self.items.remove(self.current);
+                if self.items.is_empty() {
+                    self.items.push(TextBuffer::default());
+                }
+                self.current = self.current.min(self.items.len() - 1);
+            }
+            KeyCode::Char(ch) => self.items[self.current].insert(ch),
+            KeyCode::Backspace => _ = self.items[self.current].delete_backward(),
+            KeyCode::Left => _ = self.items[self.current].move_left(),
+            KeyCode::Right => _ = self.items[self.current].move_right(),
+            KeyCode::Home => self.items[self.current].move_home(),
+            KeyCode::End => self.items[self.current].move_end(),
+            _ => return FormEvent::Ignored,
+        }
+        FormEvent::Handled
+    }
+
+    fn handle_chord(&mut self, modifiers: KeyModifiers, key: KeyCode) -> FormEvent {
+        let to = match (modifiers, key) {
+            (KeyModifiers::ALT, KeyCode::Up) if self.current > 0 => self.current - 1,
+            (KeyModifiers::ALT, KeyCode::Down) if self.current + 1 < self.items.len() => {
+                self.current + 1
+            }
+            _ => return FormEvent::Ignored,
+        };
+        self.items.swap(self.current, to);
+        self.current = to;
+        FormEvent::Handled
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, active: bool) {
+        let style = TextStyle {
+            masked: false,
+            sanitize: true,
+            selected: false,
+            allowed: None,
+        };
+        for (i, (item, y)) in self.items.iter().zip(area.top()..area.bottom()).enumerate() {
+            let current = active && i == self.current;
+            let bullet = if current { "› " } else { "• " };
+            buf.set_stringn(
+                area.x,
+                y,
+                bullet,
+                area.width as usize,
+                Style::default().dim(),
+            );
+
+            let item_area = Rect::new(area.x + 2, y, area.width.saturating_sub(2), 1);
+            if item_area.width == 0 {
+                continue;
+            }
+            let cursor = write_text(buf, item_area, item, "", style);
+            if let (Some((x, y)), true) = (cursor, current) {
+                buf.get_mut(x, y).set_style(Style::default().reversed());
+            }
+        }
+    }
+
+    fn set_value(&mut self, value: &str) {
+        *self = Self::new(value.lines());
+    }
+
+    fn value(&self) -> String {
+        self.items().join("\n")
+    }
+
+    fn height(&self) -> u16 {
+        self.items.len() as u16
+    }
+}
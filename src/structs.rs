@@ -0,0 +1,189 @@
+//! Converting between forms and serde-serializable structs.
+
+use serde::de::{self, value::MapDeserializer, DeserializeOwned, IntoDeserializer, Visitor};
+use serde::{forward_to_deserialize_any, Serialize};
+use serde_json::Value;
+
+use crate::form::FieldBuffer;
+use crate::{FieldKey, Form};
+
+impl Form {
+    /// Create a form with a field for each field of `value`, prefilled with its current value.
+    /// Strings are used as is, numbers and bools are formatted, `None` is left empty and anything
+    /// nested, like lists or structs, is written as JSON.
+    ///
+    /// Returns an error if `value` doesn't serialize to a map, e.g. if it's not a struct. See
+    /// [`Form::extract`] to convert the values back.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use serde::{Deserialize, Serialize};
+    /// # use tui_form_widget::Form;
+    /// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    /// struct Settings {
+    ///     name: String,
+    ///     port: u16,
+    ///     proxy: Option<String>,
+    /// }
+    ///
+    /// let settings = Settings { name: "server".into(), port: 8080, proxy: None };
+    /// let form = Form::from_struct(&settings).unwrap();
+    /// assert_eq!(form.status()[1].value(), "8080");
+    /// assert_eq!(form.extract::<Settings>().unwrap(), settings);
+    /// ```
+    pub fn from_struct<T: Serialize>(value: &T) -> Result<Self, serde_json::Error> {
+        let Value::Object(fields) = serde_json::to_value(value)? else {
+            return Err(serde::ser::Error::custom("expected a struct or map"));
+        };
+
+        let fields: Vec<FieldBuffer> = fields
+            .into_iter()
+            .map(|(name, val)| match val {
+                Value::String(val) => FieldBuffer::new(&name, &val),
+                Value::Null => FieldBuffer::new(&name, ""),
+                val => FieldBuffer::new(&name, &val.to_string()),
+            })
+            .collect();
+        Ok(Self::from(fields))
+    }
+}
+
+impl<K: FieldKey> Form<K> {
+    /// Deserializes the values of the form into `T`, using field names as keys. Values are parsed
+    /// into the type of the matching field of `T`, with empty values becoming `None` for optional
+    /// fields and nested values parsed as JSON, so this round-trips with [`Form::from_struct`].
+    ///
+    /// Returns an error if a value can't be parsed or a field of `T` is missing from the form.
+    pub fn extract<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        let fields = self
+            .fields
+            .iter()
+            .map(|fb| (fb.name.as_str(), FieldValue(fb.val.as_str())));
+        T::deserialize(MapDeserializer::new(fields))
+    }
+}
+
+/// Deserializes a field's value into whatever type is requested, parsing it if needed.
+struct FieldValue<'a>(&'a str);
+
+impl<'a> FieldValue<'a> {
+    fn parse<T: std::str::FromStr>(&self, ty: &str) -> Result<T, serde_json::Error>
+    where
+        T::Err: std::fmt::Display,
+    {
+        self.0
+            .trim()
+            .parse()
+            .map_err(|e| de::Error::custom(format_args!("invalid {ty} {:?}: {e}", self.0)))
+    }
+
+    /// Deserializes the value as JSON, for nested values.
+    fn json(&self) -> serde_json::Deserializer<serde_json::de::StrRead<'a>> {
+        serde_json::Deserializer::from_str(self.0)
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident: $ty:ident),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                visitor.$visit(self.parse::<$ty>(stringify!($ty))?)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for FieldValue<'de> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_str(self.0)
+    }
+
+    deserialize_parsed! {
+        deserialize_bool => visit_bool: bool,
+        deserialize_i8 => visit_i8: i8,
+        deserialize_i16 => visit_i16: i16,
+        deserialize_i32 => visit_i32: i32,
+        deserialize_i64 => visit_i64: i64,
+        deserialize_u8 => visit_u8: u8,
+        deserialize_u16 => visit_u16: u16,
+        deserialize_u32 => visit_u32: u32,
+        deserialize_u64 => visit_u64: u64,
+        deserialize_f32 => visit_f32: f32,
+        deserialize_f64 => visit_f64: f64,
+        deserialize_char => visit_char: char,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        if self.0.is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_enum(self.0.into_deserializer())
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        de::Deserializer::deserialize_seq(&mut self.json(), visitor)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        de::Deserializer::deserialize_tuple(&mut self.json(), len, visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        de::Deserializer::deserialize_tuple_struct(&mut self.json(), name, len, visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        de::Deserializer::deserialize_map(&mut self.json(), visitor)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        de::Deserializer::deserialize_struct(&mut self.json(), name, fields, visitor)
+    }
+
+    forward_to_deserialize_any! {
+        str string bytes byte_buf unit unit_struct identifier ignored_any
+    }
+}
+
+impl<'de> IntoDeserializer<'de, serde_json::Error> for FieldValue<'de> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
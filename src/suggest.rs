@@ -0,0 +1,87 @@
+/// Provides completion suggestions for a field while it's being edited.
+///
+/// Attach a provider to a field with [`FieldDef::suggestions`](crate::FieldDef::suggestions).
+/// While typing, the suggestions are listed under the field; `Up`/`Down` move through them and
+/// `Tab`/`Enter` accept the highlighted one.
+///
+/// Closures taking the current input and returning a list of suggestions implement this trait.
+/// Providers must be `Send` and `Sync` so forms can be shared across threads.
+///
+/// # Example
+///
+/// ```
+/// # use tui_form_widget::FieldDef;
+/// let users = ["alice", "bob", "carol"];
+/// let field = FieldDef::text("User").suggestions(move |input: &str| {
+///     users
+///         .iter()
+///         .filter(|u| u.starts_with(input))
+///         .map(|u| u.to_string())
+///         .collect()
+/// });
+/// ```
+pub trait SuggestionProvider: Send + Sync {
+    /// Returns suggestions for the current `input` of the field.
+    fn suggest(&self, input: &str) -> Vec<String>;
+}
+
+impl<F: Fn(&str) -> Vec<String> + Send + Sync> SuggestionProvider for F {
+    fn suggest(&self, input: &str) -> Vec<String> {
+        self(input)
+    }
+}
+
+/// A [`SuggestionProvider`] that fuzzy matches input against a fixed list of options.
+///
+/// Matching is case insensitive. Options starting with the input are suggested first, followed by
+/// options containing it, followed by options containing its chars in order.
+///
+/// # Example
+///
+/// ```
+/// # use tui_form_widget::{StaticSuggestions, SuggestionProvider};
+/// let langs = StaticSuggestions::new(vec!["Rust".into(), "Ruby".into(), "Haskell".into()]);
+/// assert_eq!(langs.suggest("ru"), vec!["Rust", "Ruby"]);
+/// assert_eq!(langs.suggest("hl"), vec!["Haskell"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct StaticSuggestions {
+    options: Vec<String>,
+}
+
+impl StaticSuggestions {
+    /// Create a provider suggesting from `options`.
+    pub fn new(options: Vec<String>) -> Self {
+        Self { options }
+    }
+}
+
+impl SuggestionProvider for StaticSuggestions {
+    fn suggest(&self, input: &str) -> Vec<String> {
+        if input.is_empty() {
+            return Vec::new();
+        }
+
+        let input = input.to_lowercase();
+        let mut matches: Vec<(usize, &String)> = self
+            .options
+            .iter()
+            .filter_map(|option| Some((match_rank(&input, &option.to_lowercase())?, option)))
+            .collect();
+        matches.sort_by_key(|(rank, _)| *rank);
+        matches.into_iter().map(|(_, o)| o.clone()).collect()
+    }
+}
+
+/// Ranks how well `option` matches `input`, lower is better. Returns `None` if it doesn't match.
+fn match_rank(input: &str, option: &str) -> Option<usize> {
+    if option.starts_with(input) {
+        return Some(0);
+    }
+    if option.contains(input) {
+        return Some(1);
+    }
+
+    let mut chars = option.chars();
+    input.chars().all(|ch| chars.any(|o| o == ch)).then_some(2)
+}
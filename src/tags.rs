@@ -0,0 +1,166 @@
+//! A field editing a set of tags, shown as chips.
+
+use std::fmt;
+
+use crossterm::event::KeyCode;
+use ratatui::prelude::*;
+
+use crate::text::TextBuffer;
+use crate::widget::{write_text, TextStyle};
+use crate::{FieldWidget, FormEvent};
+
+/// Edits a set of tags, shown as chips like `[rust] [tui]` followed by the tag being typed, added
+/// to a form with [`FieldDef::tags`](crate::FieldDef::tags).
+///
+/// While the field is active, `Enter` or `,` turns the text typed into a tag, unless it's empty or
+/// already a tag, and `Backspace` with nothing typed removes the last tag. `Enter` with nothing
+/// typed and `Esc` are handled by the form as usual. When the chips don't fit in the field, the
+/// first ones are hidden and counted instead, like `+2`.
+///
+/// The field's value has one line per tag, including any text typed but not yet turned into a tag,
+/// see [`Field::items`](crate::Field::items).
+#[derive(Clone)]
+pub struct Tags {
+    tags: Vec<String>,
+    input: TextBuffer,
+}
+
+impl Tags {
+    /// Create an editor for `tags`, skipping empty and duplicate tags.
+    pub fn new<S: AsRef<str>>(tags: impl IntoIterator<Item = S>) -> Self {
+        let mut editor = Self {
+            tags: Vec::new(),
+            input: TextBuffer::default(),
+        };
+        for tag in tags {
+            editor.add(tag.as_ref());
+        }
+        editor
+    }
+
+    /// The tags entered, including any text typed but not yet turned into a tag.
+    pub fn tags(&self) -> Vec<String> {
+        let mut tags = self.tags.clone();
+        let pending = self.input.as_str().trim();
+        if !pending.is_empty() && !tags.iter().any(|tag| tag == pending) {
+            tags.push(pending.to_string());
+        }
+        tags
+    }
+
+    /// Adds `tag` unless it's empty or already a tag. Returns whether it was added.
+    fn add(&mut self, tag: &str) -> bool {
+        let tag = tag.trim().replace('\n', " ");
+        let new = !tag.is_empty() && !self.tags.contains(&tag);
+        if new {
+            self.tags.push(tag);
+        }
+        new
+    }
+
+    fn commit(&mut self) -> FormEvent {
+        if self.input.is_empty() {
+            return FormEvent::Ignored;
+        }
+        let input = self.input.take();
+        self.add(&input);
+        FormEvent::Handled
+    }
+}
+
+impl Default for Tags {
+    fn default() -> Self {
+        Self::new(Vec::<String>::new())
+    }
+}
+
+impl fmt::Debug for Tags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tags")
+            .field("tags", &self.tags)
+            .field("input", &self.input.as_str())
+            .finish()
+    }
+}
+
+impl FieldWidget for Tags {
+    fn handle_input(&mut self, key: KeyCode) -> FormEvent {
+        match key {
+            KeyCode::Enter => return self.commit(),
+            KeyCode::Char(',') => _ = self.commit(),
+            KeyCode::Backspace if self.input.is_empty() => _ = self.tags.pop(),
+            KeyCode::Backspace => _ = self.input.delete_backward(),
+            KeyCode::Char(ch) => self.input.insert(ch),
+            KeyCode::Left => _ = self.input.move_left(),
+            KeyCode::Right => _ = self.input.move_right(),
+            KeyCode::Home => self.input.move_home(),
+            KeyCode::End => self.input.move_end(),
+            _ => return FormEvent::Ignored,
+        }
+        FormEvent::Handled
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, active: bool) {
+        let chips = self
+            .tags
+            .iter()
+            .map(|tag| format!("[{tag}]"))
+            .collect::<Vec<_>>();
+        let widths = chips
+            .iter()
+            .map(|chip| Span::raw(chip.as_str()).width() as u16 + 1)
+            .collect::<Vec<_>>();
+
+        // hide the first chips until the rest fit with room for typing
+        let room = 1 + self.input.as_str().chars().count() as u16;
+        let mut first = 0;
+        let mut hidden = String::new();
+        while first < chips.len()
+            && widths[first..].iter().sum::<u16>() + hidden.len() as u16 + room > area.width
+        {
+            first += 1;
+            hidden = format!("+{first} ");
+        }
+
+        let mut x = area.x;
+        x = buf
+            .set_stringn(
+                x,
+                area.y,
+                &hidden,
+                area.width as usize,
+                Style::default().dim(),
+            )
+            .0;
+        for chip in &chips[first..] {
+            let width = area.right().saturating_sub(x) as usize;
+            x = buf
+                .set_stringn(x, area.y, chip, width, Style::default().bold())
+                .0;
+            x = (x + 1).min(area.right());
+        }
+
+        let input_area = Rect::new(x, area.y, area.right() - x, 1);
+        if input_area.width == 0 {
+            return;
+        }
+        let style = TextStyle {
+            masked: false,
+            sanitize: true,
+            selected: false,
+            allowed: None,
+        };
+        let cursor = write_text(buf, input_area, &self.input, "", style);
+        if let (Some((x, y)), true) = (cursor, active) {
+            buf.get_mut(x, y).set_style(Style::default().reversed());
+        }
+    }
+
+    fn value(&self) -> String {
+        self.tags().join("\n")
+    }
+
+    fn set_value(&mut self, value: &str) {
+        *self = Self::new(value.lines());
+    }
+}
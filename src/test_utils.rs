@@ -0,0 +1,255 @@
+//! Helpers for testing forms.
+//!
+//! [`render`] draws a form into the buffer of a [`TestBackend`] of the given size, and the
+//! `assert_*` functions check the result, printing the whole buffer when they fail. To test how a
+//! form behaves, drive it with scripted input using a [`FormTester`].
+//!
+//! Rendering is deterministic: forms have no time-dependent visuals like blinking cursors, the
+//! cursor is drawn as a reversed cell rather than by the terminal, and the glyphs used don't depend
+//! on the environment. The same form rendered to the same size always produces the same buffer, so
+//! [`snapshot`]s of it can be compared across runs.
+//!
+//! # Example
+//!
+//! ```
+//! # use ratatui::prelude::*;
+//! # use tui_form_widget::{test_utils, Form, FormSelection};
+//! let mut form = Form::from(vec!["Name"]);
+//! form.select(FormSelection::Hovered(0));
+//!
+//! let buf = test_utils::render(&form, 10, 4);
+//! test_utils::assert_lines(
+//!     &buf,
+//!     &[
+//!         "╭Name────╮",
+//!         "│        │",
+//!         "╰────────╯",
+//!         "          ",
+//!     ],
+//! );
+//! test_utils::assert_style(&buf, 0, 0, Style::default().cyan());
+//! ```
+
+use crossterm::event::KeyCode;
+use ratatui::backend::TestBackend;
+use ratatui::prelude::*;
+
+use crate::{FieldKey, FieldRef, Form, FormEvent, FormSelection};
+
+/// Renders `form` to a [`TestBackend`] of `width` by `height` cells and returns its buffer.
+pub fn render<K: FieldKey>(form: &Form<K>, width: u16, height: u16) -> Buffer {
+    let mut terminal =
+        Terminal::new(TestBackend::new(width, height)).expect("test backend can't fail");
+    terminal
+        .draw(|f| f.render_widget(form.widget(), f.size()))
+        .expect("test backend can't fail");
+    terminal.backend().buffer().clone()
+}
+
+/// Returns the symbols of each row of `buf`.
+pub fn lines(buf: &Buffer) -> Vec<String> {
+    let area = buf.area;
+    (area.top()..area.bottom())
+        .map(|y| {
+            (area.left()..area.right())
+                .map(|x| buf.get(x, y).symbol.as_str())
+                .collect()
+        })
+        .collect()
+}
+
+/// Returns the symbols of `buf` as text, one line per row, for comparing against a snapshot.
+///
+/// # Example
+///
+/// ```
+/// # use tui_form_widget::{test_utils, Form};
+/// let form = Form::from(vec![("Name", "Ferris")]);
+/// let snapshot = test_utils::snapshot(&test_utils::render(&form, 12, 3));
+/// assert_eq!(snapshot, "╭Name──────╮\n│Ferris    │\n╰──────────╯");
+/// ```
+pub fn snapshot(buf: &Buffer) -> String {
+    lines(buf).join("\n")
+}
+
+/// Asserts that the rows of `buf` are `expected`.
+#[track_caller]
+pub fn assert_lines(buf: &Buffer, expected: &[&str]) {
+    let actual = lines(buf);
+    assert!(
+        actual == expected,
+        "buffer doesn't match\nexpected:\n{}\nactual:\n{}",
+        expected.join("\n"),
+        actual.join("\n"),
+    );
+}
+
+/// Asserts that the cell at `(x, y)` shows `symbol`.
+#[track_caller]
+pub fn assert_symbol(buf: &Buffer, x: u16, y: u16, symbol: &str) {
+    let actual = &buf.get(x, y).symbol;
+    assert!(
+        actual == symbol,
+        "cell ({x}, {y}) is {actual:?}, expected {symbol:?}\nbuffer:\n{}",
+        lines(buf).join("\n"),
+    );
+}
+
+/// Asserts that the cell at `(x, y)` has `style`. Only the colors and modifiers set in `style`
+/// are compared, like the style would be applied to the cell.
+#[track_caller]
+pub fn assert_style(buf: &Buffer, x: u16, y: u16, style: Style) {
+    let actual = buf.get(x, y).style();
+    assert!(
+        actual.patch(style) == actual,
+        "cell ({x}, {y}) has {actual:?}, expected {style:?}\nbuffer:\n{}",
+        lines(buf).join("\n"),
+    );
+}
+
+/// Drives a [`Form`] through a script of input, asserting on its state along the way.
+///
+/// Input goes through [`Form::input`], so keys behave exactly as they would in an application
+/// using the form's [`KeyMap`](crate::KeyMap). Methods take and return the tester so scripts can be
+/// chained, and assertions panic with the form's state when they fail.
+///
+/// # Example
+///
+/// ```
+/// # use tui_form_widget::{test_utils::FormTester, Form, FormSelection};
+/// FormTester::new(Form::from(vec!["Name", "Email"]))
+///     .keys("jj")
+///     .enter()
+///     .type_str("hello")
+///     .assert_selected(FormSelection::Active(1))
+///     .assert_value("Email", "hello")
+///     .submit()
+///     .assert_invalid("Name")
+///     .assert_valid("Email");
+/// ```
+#[derive(Debug)]
+pub struct FormTester<K = String> {
+    form: Form<K>,
+    last_event: Option<FormEvent>,
+}
+
+impl<K: FieldKey> FormTester<K> {
+    /// Create a tester driving `form`.
+    pub fn new(form: Form<K>) -> Self {
+        Self {
+            form,
+            last_event: None,
+        }
+    }
+
+    /// Send `key` to the form.
+    pub fn key(mut self, key: KeyCode) -> Self {
+        self.last_event = Some(self.form.input(key));
+        self
+    }
+
+    /// Send each char of `keys` to the form as a key press.
+    pub fn keys(self, keys: &str) -> Self {
+        keys.chars()
+            .fold(self, |tester, ch| tester.key(KeyCode::Char(ch)))
+    }
+
+    /// Send `Enter` to the form.
+    pub fn enter(self) -> Self {
+        self.key(KeyCode::Enter)
+    }
+
+    /// Send `Esc` to the form.
+    pub fn esc(self) -> Self {
+        self.key(KeyCode::Esc)
+    }
+
+    /// Send `Backspace` to the form.
+    pub fn backspace(self) -> Self {
+        self.key(KeyCode::Backspace)
+    }
+
+    /// Type `text` into the active field, see [`Form::type_str`].
+    pub fn type_str(mut self, text: &str) -> Self {
+        self.last_event = Some(self.form.type_str(text));
+        self
+    }
+
+    /// Submit the form.
+    pub fn submit(mut self) -> Self {
+        self.form.submit();
+        self
+    }
+
+    /// The form being tested.
+    pub fn form(&self) -> &Form<K> {
+        &self.form
+    }
+
+    /// Stop testing, returning the form.
+    pub fn into_form(self) -> Form<K> {
+        self.form
+    }
+
+    /// Asserts that the last key sent was handled with `event`.
+    #[track_caller]
+    pub fn assert_event(self, event: FormEvent) -> Self {
+        assert_eq!(self.last_event, Some(event), "unexpected event");
+        self
+    }
+
+    /// Asserts that `selection` is selected.
+    #[track_caller]
+    pub fn assert_selected(self, selection: FormSelection) -> Self {
+        assert_eq!(*self.form.selected(), selection, "unexpected selection");
+        self
+    }
+
+    /// Asserts that `field` has the value `expected`.
+    #[track_caller]
+    pub fn assert_value<'a>(self, field: impl Into<FieldRef<'a, K>>, expected: &str) -> Self {
+        let field = self.field_index(&field.into());
+        assert_eq!(
+            self.form.value(field),
+            Some(expected),
+            "unexpected value of field {field}"
+        );
+        self
+    }
+
+    /// Asserts that `field` is currently considered valid.
+    #[track_caller]
+    pub fn assert_valid<'a>(self, field: impl Into<FieldRef<'a, K>>) -> Self {
+        self.assert_validity(field.into(), true)
+    }
+
+    /// Asserts that `field` is currently considered invalid.
+    #[track_caller]
+    pub fn assert_invalid<'a>(self, field: impl Into<FieldRef<'a, K>>) -> Self {
+        self.assert_validity(field.into(), false)
+    }
+
+    #[track_caller]
+    fn assert_validity(self, field: FieldRef<'_, K>, valid: bool) -> Self {
+        let i = self.field_index(&field);
+        let status = self
+            .form
+            .field(i)
+            .expect("field_index returns existing fields");
+        assert!(
+            status.is_valid() == valid,
+            "expected field {field:?} to be {}, error: {:?}",
+            if valid { "valid" } else { "invalid" },
+            status.error(),
+        );
+        self
+    }
+
+    #[track_caller]
+    fn field_index(&self, field: &FieldRef<'_, K>) -> usize {
+        match self.form.field_index(field.clone()) {
+            Some(i) => i,
+            None => panic!("no field {field:?} in {:?}", self.form),
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use std::sync::OnceLock;
+
+use crate::secret;
+
+/// Text of a field, stored as a gap buffer split at the cursor so editing anywhere in the text is
+/// cheap regardless of its length.
+///
+/// The text before the cursor is stored in order, the text after it in reverse, so inserting,
+/// deleting and moving the cursor by a char only ever pushes or pops the end of a `String`. A
+/// contiguous copy is only built when the text is read while the cursor isn't at the end, and is
+/// reused until the next edit.
+#[derive(Clone, Default)]
+pub(crate) struct TextBuffer {
+    before: String,
+    after_rev: String,
+    joined: OnceLock<String>,
+    pub(crate) secret: bool,
+}
+
+impl TextBuffer {
+    pub(crate) fn new(val: &str) -> Self {
+        Self {
+            before: val.to_string(),
+            after_rev: String::new(),
+            joined: OnceLock::new(),
+            secret: false,
+        }
+    }
+
+    /// The whole text.
+    pub(crate) fn as_str(&self) -> &str {
+        if self.after_rev.is_empty() {
+            return &self.before;
+        }
+        self.joined.get_or_init(|| {
+            let mut joined = String::with_capacity(self.before.len() + self.after_rev.len());
+            joined.push_str(&self.before);
+            joined.extend(self.after_rev.chars().rev());
+            joined
+        })
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.before.is_empty() && self.after_rev.is_empty()
+    }
+
+    /// Text before the cursor.
+    pub(crate) fn before(&self) -> &str {
+        &self.before
+    }
+
+    /// Chars after the cursor, in order.
+    pub(crate) fn after(&self) -> impl Iterator<Item = char> + '_ {
+        self.after_rev.chars().rev()
+    }
+
+    /// Inserts `ch` at the cursor.
+    pub(crate) fn insert(&mut self, ch: char) {
+        self.edited();
+        push(&mut self.before, ch, self.secret);
+    }
+
+    /// Removes the char before the cursor.
+    pub(crate) fn delete_backward(&mut self) -> Option<char> {
+        self.edited();
+        pop(&mut self.before, self.secret)
+    }
+
+    /// Removes the char after the cursor.
+    pub(crate) fn delete_forward(&mut self) -> Option<char> {
+        self.edited();
+        pop(&mut self.after_rev, self.secret)
+    }
+
+    /// Moves the cursor one char left. Returns `false` if it's already at the start.
+    pub(crate) fn move_left(&mut self) -> bool {
+        let Some(ch) = pop(&mut self.before, self.secret) else {
+            return false;
+        };
+        push(&mut self.after_rev, ch, self.secret);
+        true
+    }
+
+    /// Moves the cursor one char right. Returns `false` if it's already at the end.
+    pub(crate) fn move_right(&mut self) -> bool {
+        let Some(ch) = pop(&mut self.after_rev, self.secret) else {
+            return false;
+        };
+        push(&mut self.before, ch, self.secret);
+        true
+    }
+
+    /// Moves the cursor to the start of the previous word, where words are runs of alphanumeric
+    /// chars and `_`.
+    pub(crate) fn move_word_left(&mut self) {
+        while self
+            .before
+            .chars()
+            .next_back()
+            .is_some_and(|ch| !is_word(ch))
+        {
+            self.move_left();
+        }
+        while self.before.chars().next_back().is_some_and(is_word) {
+            self.move_left();
+        }
+    }
+
+    /// Moves the cursor to the end of the next word, see [`TextBuffer::move_word_left`].
+    pub(crate) fn move_word_right(&mut self) {
+        while self
+            .after_rev
+            .chars()
+            .next_back()
+            .is_some_and(|ch| !is_word(ch))
+        {
+            self.move_right();
+        }
+        while self.after_rev.chars().next_back().is_some_and(is_word) {
+            self.move_right();
+        }
+    }
+
+    /// Moves the cursor to the start of the current line.
+    pub(crate) fn move_home(&mut self) {
+        while !self.before.ends_with('\n') && self.move_left() {}
+    }
+
+    /// Moves the cursor to the end of the current line.
+    pub(crate) fn move_end(&mut self) {
+        while !self.after_rev.ends_with('\n') && self.move_right() {}
+    }
+
+    /// Moves the cursor to the same column on the previous line, or the end of it if it's shorter.
+    pub(crate) fn move_up(&mut self) {
+        let col = self.column();
+        self.move_home();
+        if !self.move_left() {
+            return;
+        }
+        let len = self.column();
+        for _ in col..len {
+            self.move_left();
+        }
+    }
+
+    /// Moves the cursor to the same column on the next line, or the end of it if it's shorter.
+    pub(crate) fn move_down(&mut self) {
+        let col = self.column();
+        self.move_end();
+        if !self.move_right() {
+            return;
+        }
+        for _ in 0..col {
+            if self.after_rev.ends_with('\n') || !self.move_right() {
+                break;
+            }
+        }
+    }
+
+    /// Replaces the text, placing the cursor at the end.
+    pub(crate) fn set(&mut self, val: String) {
+        self.clear();
+        self.before = val;
+    }
+
+    /// Takes the text out of the buffer, leaving it empty.
+    pub(crate) fn take(&mut self) -> String {
+        if !self.after_rev.is_empty() {
+            let val = self.as_str().to_string();
+            self.clear();
+            return val;
+        }
+        self.edited();
+        std::mem::take(&mut self.before)
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.edited();
+        secret::clear(&mut self.before);
+        secret::clear(&mut self.after_rev);
+    }
+
+    /// Number of chars between the start of the current line and the cursor.
+    fn column(&self) -> usize {
+        self.before
+            .chars()
+            .rev()
+            .take_while(|ch| *ch != '\n')
+            .count()
+    }
+
+    /// Drops the contiguous copy of the text, which is out of date after an edit.
+    fn edited(&mut self) {
+        if let Some(mut joined) = self.joined.take() {
+            secret::clear(&mut joined);
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for TextBuffer {
+    fn drop(&mut self) {
+        if self.secret {
+            self.clear();
+        }
+    }
+}
+
+fn is_word(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_'
+}
+
+fn push(buf: &mut String, ch: char, secret: bool) {
+    if secret {
+        secret::push(buf, ch);
+    } else {
+        buf.push(ch);
+    }
+}
+
+fn pop(buf: &mut String, secret: bool) -> Option<char> {
+    if !secret {
+        return buf.pop();
+    }
+    let ch = buf.chars().next_back()?;
+    secret::pop(buf);
+    Some(ch)
+}
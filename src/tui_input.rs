@@ -0,0 +1,45 @@
+//! Using [`tui_input::Input`] as a field, with the `tui-input` feature.
+
+use crossterm::event::{Event, KeyCode, KeyEvent};
+use ratatui::{prelude::*, widgets::*};
+use tui_input::backend::crossterm::to_input_request;
+use tui_input::Input;
+
+use crate::{FieldWidget, FormEvent};
+
+/// Edits a single-line field with an [`Input`], reusing its cursor movement and scrolling. Keys the
+/// input doesn't handle, like `Enter` and `Esc`, are handled by the form as usual.
+///
+/// Add it to a form with [`FieldDef::custom`](crate::FieldDef::custom).
+impl FieldWidget for Input {
+    fn handle_input(&mut self, key: KeyCode) -> FormEvent {
+        match to_input_request(&Event::Key(KeyEvent::from(key))) {
+            Some(request) => {
+                self.handle(request);
+                FormEvent::Handled
+            }
+            None => FormEvent::Ignored,
+        }
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, active: bool) {
+        // leave room for the cursor past the end of the value
+        let scroll = self.visual_scroll(area.width.saturating_sub(1) as usize);
+        Paragraph::new(self.value())
+            .scroll((0, scroll as u16))
+            .render(area, buf);
+        if active {
+            let x = area.x + (self.visual_cursor() - scroll) as u16;
+            buf.get_mut(x.min(area.right() - 1), area.y)
+                .set_style(Style::default().reversed());
+        }
+    }
+
+    fn value(&self) -> String {
+        Input::value(self).to_string()
+    }
+
+    fn set_value(&mut self, value: &str) {
+        *self = std::mem::take(self).with_value(value.to_string());
+    }
+}
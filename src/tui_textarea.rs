@@ -0,0 +1,51 @@
+//! Using [`tui_textarea::TextArea`] as a field, with the `tui-textarea` feature.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{prelude::*, widgets::Widget};
+use tui_textarea::TextArea;
+
+use crate::{FieldWidget, FormEvent};
+
+/// Edits a multiline field with a [`TextArea`], which handles all keys except `Esc`, so the form
+/// only handles focus, validation and layout. The cursor is only shown while the field is active.
+///
+/// Add it to a form with [`FieldDef::custom`](crate::FieldDef::custom). The field grows with the
+/// number of lines in the text area.
+impl FieldWidget for TextArea<'static> {
+    fn handle_input(&mut self, key: KeyCode) -> FormEvent {
+        if key == KeyCode::Esc {
+            return FormEvent::Ignored;
+        }
+        self.input(KeyEvent::from(key));
+        FormEvent::Handled
+    }
+
+    fn render(&self, area: Rect, buf: &mut Buffer, active: bool) {
+        if active {
+            Widget::render(widget(self), area, buf);
+        } else {
+            let mut textarea = self.clone();
+            textarea.set_cursor_style(textarea.style());
+            textarea.set_cursor_line_style(Style::default());
+            Widget::render(widget(&textarea), area, buf);
+        }
+    }
+
+    fn value(&self) -> String {
+        self.lines().join("\n")
+    }
+
+    fn set_value(&mut self, value: &str) {
+        self.select_all();
+        self.insert_str(value);
+    }
+
+    fn height(&self) -> u16 {
+        self.lines().len().try_into().unwrap_or(u16::MAX)
+    }
+}
+
+/// Renders `textarea`, shortening its lifetime to that of the borrow as its widget requires.
+fn widget<'a>(textarea: &'a TextArea<'a>) -> impl Widget + 'a {
+    textarea.widget()
+}
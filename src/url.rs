@@ -0,0 +1,44 @@
+//! Validating URL fields, using the `url` crate when the `url` feature is enabled.
+
+/// Returns `val` with `scheme://` prepended if it's not empty and doesn't have a scheme, or `None`
+/// if it doesn't need one.
+pub(crate) fn with_default_scheme(val: &str, scheme: &Option<String>) -> Option<String> {
+    match scheme {
+        Some(scheme) if !val.is_empty() && !val.contains("://") => {
+            Some(format!("{scheme}://{val}"))
+        }
+        _ => None,
+    }
+}
+
+/// Checks that `val` is a URL with one of `schemes` and a host, returning a message describing
+/// the problem if it isn't.
+pub(crate) fn validate(val: &str, schemes: &[String]) -> Result<(), String> {
+    let Some((scheme, rest)) = val.split_once("://") else {
+        return Err(schemes_message(schemes));
+    };
+    if !schemes.iter().any(|s| s.eq_ignore_ascii_case(scheme)) {
+        return Err(schemes_message(schemes));
+    }
+    if val.contains(char::is_whitespace) {
+        return Err("URLs can't contain spaces".to_string());
+    }
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    if host.is_empty() {
+        return Err("URLs need a host, like example.com".to_string());
+    }
+    #[cfg(feature = "url")]
+    if let Err(err) = ::url::Url::parse(val) {
+        return Err(format!("Invalid URL: {err}"));
+    }
+    Ok(())
+}
+
+fn schemes_message(schemes: &[String]) -> String {
+    let schemes = schemes
+        .iter()
+        .map(|s| format!("{s}://"))
+        .collect::<Vec<_>>()
+        .join(" or ");
+    format!("URLs must start with {schemes}")
+}
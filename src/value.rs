@@ -0,0 +1,127 @@
+//! Values of fields typed according to their kind.
+
+use std::net::IpAddr;
+use std::time::Duration;
+
+use crate::duration;
+
+/// Value of a field, typed according to the kind of field, from
+/// [`Field::typed_value`](crate::Field::typed_value).
+///
+/// Values that can't be parsed as their field's type, like a half typed number or an empty
+/// duration, are [`Text`](Self::Text).
+///
+/// # Example
+///
+/// ```
+/// # use tui_form_widget::{FieldDef, FieldValue, Form, StringList};
+/// let form = Form::from(vec![
+///     FieldDef::number("Port").value("8080"),
+///     FieldDef::string_list("Hosts", StringList::new(["alpha", "beta"])),
+///     FieldDef::number("Retries"),
+/// ]);
+/// let status = form.status();
+/// assert_eq!(status[0].typed_value().as_int(), Some(8080));
+/// assert_eq!(status[1].typed_value().as_list(), Some(&["alpha", "beta"].map(String::from)[..]));
+/// assert_eq!(status[2].typed_value(), FieldValue::Text(String::new()));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FieldValue {
+    /// Value of a text field, or of any field whose value can't be parsed as its type.
+    Text(String),
+    /// Value of a [number](crate::FieldDef::number) field.
+    Int(i64),
+    /// Value of a [duration](crate::FieldDef::duration) field.
+    Duration(Duration),
+    /// Value of an [IP address](crate::FieldDef::ip) field.
+    Ip(IpAddr),
+    /// Entries of a [string list](crate::FieldDef::string_list) or [tags](crate::FieldDef::tags)
+    /// field.
+    List(Vec<String>),
+    /// Pairs of a [key/value](crate::FieldDef::key_values) field.
+    Pairs(Vec<(String, String)>),
+}
+
+/// Type a field's value is parsed as by [`FieldValue::parse`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum ValueType {
+    #[default]
+    Text,
+    Int,
+    Duration,
+    Ip,
+    List,
+    Pairs,
+}
+
+impl FieldValue {
+    /// Parses `val` as `ty`, falling back to [`Text`](Self::Text) if it isn't one.
+    pub(crate) fn parse(ty: ValueType, val: &str) -> Self {
+        let typed = match ty {
+            ValueType::Text => None,
+            ValueType::Int => val.parse().ok().map(Self::Int),
+            ValueType::Duration => duration::parse(val).ok().map(Self::Duration),
+            ValueType::Ip => val.parse().ok().map(Self::Ip),
+            ValueType::List => Some(Self::List(val.lines().map(str::to_string).collect())),
+            ValueType::Pairs => Some(Self::Pairs(pairs(val))),
+        };
+        typed.unwrap_or_else(|| Self::Text(val.to_string()))
+    }
+
+    /// The text, if this is [`Text`](Self::Text).
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// The number, if this is an [`Int`](Self::Int).
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            Self::Int(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// The duration, if this is a [`Duration`](Self::Duration).
+    pub fn as_duration(&self) -> Option<Duration> {
+        match self {
+            Self::Duration(duration) => Some(*duration),
+            _ => None,
+        }
+    }
+
+    /// The address, if this is an [`Ip`](Self::Ip).
+    pub fn as_ip(&self) -> Option<IpAddr> {
+        match self {
+            Self::Ip(ip) => Some(*ip),
+            _ => None,
+        }
+    }
+
+    /// The entries, if this is a [`List`](Self::List).
+    pub fn as_list(&self) -> Option<&[String]> {
+        match self {
+            Self::List(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// The pairs, if this is [`Pairs`](Self::Pairs).
+    pub fn as_pairs(&self) -> Option<&[(String, String)]> {
+        match self {
+            Self::Pairs(pairs) => Some(pairs),
+            _ => None,
+        }
+    }
+}
+
+/// Splits `val` into pairs, one per `key=value` line. Lines without a `=` are skipped.
+pub(crate) fn pairs(val: &str) -> Vec<(String, String)> {
+    val.lines()
+        .filter_map(|line| line.split_once('='))
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
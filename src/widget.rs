@@ -1,165 +1,979 @@
-use std::rc::Rc;
+use std::borrow::Cow;
+use std::sync::{Mutex, MutexGuard, PoisonError};
 
-use crate::{Form, FormSelection};
+use crate::field::FieldKind;
+use crate::form::{CharFilter, FieldBuffer};
+use crate::keymap::{chord_label, key_label};
+use crate::text::TextBuffer;
+use crate::{Action, FieldKey, Form, FormSelection, SelectDisplay};
+use crossterm::event::KeyCode;
+use ratatui::widgets::block::Position;
 use ratatui::{prelude::*, widgets::*};
+use unicode_width::UnicodeWidthChar;
 
-pub struct Renderer<'a>(&'a Form);
+pub struct Renderer<'a, K = String>(&'a Form<K>);
 
-impl<'a> Renderer<'a> {
-    pub fn new(form: &'a Form) -> Self {
+impl<'a, K: FieldKey> Renderer<'a, K> {
+    pub fn new(form: &'a Form<K>) -> Self {
         Renderer(form)
     }
 }
 
-impl<'a> Widget for Renderer<'a> {
+impl<'a, K: FieldKey> Widget for Renderer<'a, K> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        Block::new().title("Form").render(area, buf);
-        let constraints: Vec<Constraint> = self
-            .0
-            .fields
+        let area = area.intersection(buf.area);
+        // e.g. while the terminal is resized down to nothing
+        if area.area() == 0 {
+            self.update_layout(Vec::new());
+            return;
+        }
+        self.render_form(area, buf);
+    }
+}
+
+impl<'a, K: FieldKey> Renderer<'a, K> {
+    /// Returns the label to display for `key`, see [`LabelProvider`](crate::LabelProvider).
+    fn label<'k>(&self, key: &'k str) -> Cow<'k, str> {
+        match self.0.labels.as_ref().and_then(|labels| labels.label(key)) {
+            Some(label) => Cow::Owned(label),
+            None => Cow::Borrowed(key),
+        }
+    }
+
+    /// Renders the form into `area`.
+    fn render_form(&self, area: Rect, buf: &mut Buffer) {
+        buf.set_stringn(
+            area.x,
+            area.y,
+            self.label("Form"),
+            area.width as usize,
+            Style::default(),
+        );
+
+        let layout = self.layout(area);
+        let mut popup = None;
+        let mut tooltip = None;
+        for &(i, field_area) in &layout {
+            let fb = &self.0.fields[i];
+            if let Some(list) = self.draw_field(i, fb, field_area, buf) {
+                popup = Some((field_area, list, None));
+            }
+            if let (Some(browser), FormSelection::Active(a)) = (&self.0.browser, self.0.selected())
+            {
+                if *a == i {
+                    let list = (&browser.labels[..], Some(browser.highlighted));
+                    popup = Some((field_area, list, Some(browser.title())));
+                }
+            }
+            if self.0.show_tooltip && *self.0.selected() == FormSelection::Hovered(i) {
+                tooltip = fb.tooltip.as_deref().map(|text| (field_area, text));
+            }
+        }
+
+        let y = layout
+            .last()
+            .map_or(area.y, |(_, field_area)| field_area.bottom());
+        if self.0.show_hints && y < area.bottom() {
+            self.render_hints(Rect::new(area.x, y, area.width, 1), buf);
+        }
+        self.update_layout(layout);
+
+        if let Some((field_area, (options, highlighted), title)) = popup {
+            self.render_popup_list(field_area, buf, options, highlighted, title.as_deref());
+        }
+        if let Some((field_area, text)) = tooltip {
+            self.render_tooltip(field_area, buf, text);
+        }
+
+        if self.0.show_help {
+            self.render_help(area, buf);
+        }
+        if !self.0.focused {
+            buf.set_style(area, self.0.unfocused_style);
+        }
+    }
+
+    /// Returns the indices and areas of the fields to render in `area`, reusing the last layout
+    /// until the form changes or is rendered to a different area.
+    fn layout(&self, area: Rect) -> Vec<(usize, Rect)> {
+        if let Some((cached_area, layout)) = &self.0.render_state.lock().cached_layout {
+            if *cached_area == area {
+                return layout.clone();
+            }
+        }
+
+        let fields_height = area.height - u16::from(self.0.show_hints && area.height > 0);
+        let first = self.first_visible(fields_height);
+        let mut layout = Vec::new();
+        let mut y = area.y;
+        for (i, fb) in self.0.fields.iter().enumerate().skip(first) {
+            let height = self.field_height(i, fb).min(area.y + fields_height - y);
+            if height == 0 {
+                break;
+            }
+            layout.push((i, Rect::new(area.x, y, area.width, height)));
+            y += height;
+        }
+        self.0.render_state.lock().cached_layout = Some((area, layout.clone()));
+        layout
+    }
+
+    /// Draws field `i` into `area`, reusing the cells drawn for it last time if it hasn't changed
+    /// since. Fields drawn by the application, through [`FieldDef::render_with`] or a
+    /// [`LabelProvider`](crate::LabelProvider), can change without the form knowing, so they're
+    /// drawn afresh every time.
+    ///
+    /// [`FieldDef::render_with`]: crate::FieldDef::render_with
+    fn draw_field(
+        &self,
+        i: usize,
+        fb: &'a FieldBuffer,
+        area: Rect,
+        buf: &mut Buffer,
+    ) -> Option<OptionList<'a>> {
+        if fb.render.is_some() || self.0.labels.is_some() {
+            return self.render_field(i, fb, area, buf);
+        }
+        if let Some(Some(cells)) = self.0.render_state.lock().fields.get(i) {
+            if cells.area == area {
+                cells.apply(buf);
+                return None;
+            }
+        }
+
+        let mut scratch = Buffer::filled(area, &unpainted());
+        let options = self.render_field(i, fb, area, &mut scratch);
+        let cells = CachedCells::new(&scratch);
+        cells.apply(buf);
+        // open options are drawn over other fields, so the field is drawn afresh while they are
+        if options.is_none() {
+            let fields = &mut self.0.render_state.lock().fields;
+            if fields.len() <= i {
+                fields.resize(i + 1, None);
+            }
+            fields[i] = Some(cells);
+        }
+        options
+    }
+}
+
+/// Renders a [`Form`] as a centered modal, clearing whatever was drawn behind it.
+pub struct PopupRenderer<'a, K = String> {
+    form: &'a Form<K>,
+    width: u16,
+    height: u16,
+}
+
+impl<'a, K> PopupRenderer<'a, K> {
+    pub fn new(form: &'a Form<K>, width: u16, height: u16) -> Self {
+        PopupRenderer {
+            form,
+            width,
+            height,
+        }
+    }
+}
+
+impl<'a, K: FieldKey> Widget for PopupRenderer<'a, K> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = centered_rect(self.width, self.height, area);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_type(self.form.border_type);
+        let inner = block.inner(area);
+
+        Clear.render(area, buf);
+        block.render(area, buf);
+        Renderer::new(self.form).render(inner, buf);
+    }
+}
+
+/// State the renderer keeps between frames.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct RenderState {
+    /// Index of the first field rendered.
+    pub(crate) first_visible: usize,
+    /// Indices and areas of the fields in the last frame.
+    pub(crate) layout: Vec<(usize, Rect)>,
+    /// Area and layout of the fields in the last frame, reused until the form changes or is
+    /// rendered to a different area.
+    cached_layout: Option<(Rect, Vec<(usize, Rect)>)>,
+    /// Cells drawn for each field in the last frame, by index, reused until the field changes.
+    fields: Vec<Option<CachedCells>>,
+}
+
+/// Background a field is rendered onto to find out which cells it paints, which the field is never
+/// drawn with itself.
+fn unpainted() -> buffer::Cell {
+    let mut cell = buffer::Cell::default();
+    cell.set_symbol("\u{e000}")
+        .set_fg(Color::Indexed(0))
+        .set_bg(Color::Indexed(0));
+    cell
+}
+
+/// Changes rendering a field makes to the cells of its area, leaving out whatever is drawn
+/// underneath it, so it can be drawn again over a different background.
+#[derive(Clone, Debug)]
+struct CachedCells {
+    area: Rect,
+    /// For each cell of the area, row by row, the symbol written, if any, and the style the cell
+    /// was patched with.
+    cells: Vec<(Option<String>, Style)>,
+}
+
+impl CachedCells {
+    /// Works out what was painted onto the [`unpainted`] background of `buf`.
+    fn new(buf: &Buffer) -> Self {
+        let background = unpainted();
+        let cells = buf
+            .content
             .iter()
-            .map(|_| Constraint::Max(3))
-            .chain([Constraint::Max(1)])
+            .map(|cell| {
+                let symbol = (cell.symbol != background.symbol).then(|| cell.symbol.clone());
+                let mut style = Style::new().add_modifier(cell.modifier);
+                if cell.fg != background.fg {
+                    style = style.fg(cell.fg);
+                }
+                if cell.bg != background.bg {
+                    style = style.bg(cell.bg);
+                }
+                (symbol, style)
+            })
             .collect();
+        Self {
+            area: buf.area,
+            cells,
+        }
+    }
+
+    fn apply(&self, buf: &mut Buffer) {
+        let positions = (self.area.top()..self.area.bottom())
+            .flat_map(|y| (self.area.left()..self.area.right()).map(move |x| (x, y)));
+        for ((x, y), (symbol, style)) in positions.zip(&self.cells) {
+            let cell = buf.get_mut(x, y);
+            if let Some(symbol) = symbol {
+                cell.set_symbol(symbol);
+            }
+            cell.set_style(*style);
+        }
+    }
+}
 
-        let area = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints(constraints)
-            .split(area);
+/// [`RenderState`] that can be updated while rendering through a shared reference to the form.
+#[derive(Debug, Default)]
+pub(crate) struct RenderCache(Mutex<RenderState>);
 
-        self.render_fields(area, buf);
+impl RenderCache {
+    pub(crate) fn lock(&self) -> MutexGuard<'_, RenderState> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
     }
+
+    fn get_mut(&mut self) -> &mut RenderState {
+        self.0.get_mut().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Drops everything cached from the last frame, e.g. because the form's style changed.
+    pub(crate) fn invalidate(&mut self) {
+        let state = self.get_mut();
+        state.cached_layout = None;
+        state.fields.clear();
+    }
+
+    /// Drops the cells cached for field `i`, e.g. because its value changed, along with the
+    /// layout, which its height may change.
+    pub(crate) fn invalidate_field(&mut self, i: usize) {
+        let state = self.get_mut();
+        state.cached_layout = None;
+        if let Some(cells) = state.fields.get_mut(i) {
+            *cells = None;
+        }
+    }
+}
+
+impl Clone for RenderCache {
+    fn clone(&self) -> Self {
+        Self(Mutex::new(self.lock().clone()))
+    }
+}
+
+/// Returns a `width` x `height` rect centered in `area`, shrunk to fit if needed.
+pub(crate) fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
 }
 
-enum FieldRenderType {
+/// State a field is rendered in, which decides its style.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FieldState {
+    /// The field isn't selected and has no problems shown.
     Normal,
+    /// The field isn't selected and is shown as invalid.
     Invalid,
+    /// The field isn't selected and has a warning shown.
+    Warning,
+    /// The field is hovered.
     Hovered,
+    /// The field is being edited.
     Active,
 }
 
-impl<'a> Renderer<'a> {
-    fn render_fields(&self, area: Rc<[Rect]>, buf: &mut Buffer) {
-        let fields = self.0.status();
-        fields.iter().enumerate().for_each(|(i, field)| {
-            let is_invalid = !field.is_valid() && self.0.submitted;
-            let hovered = if let FormSelection::Hovered(f) = self.0.selected() {
-                *f == i
-            } else {
-                false
-            };
+/// Options listed under a field, along with the highlighted option.
+type OptionList<'a> = (&'a [String], Option<usize>);
 
-            let active = if let FormSelection::Active(f) = self.0.selected() {
-                *f == i
-            } else {
-                false
-            };
+impl<'a, K: FieldKey> Renderer<'a, K> {
+    fn render_type(&self, i: usize, fb: &FieldBuffer) -> FieldState {
+        // the selection isn't shown while the form doesn't have the focus
+        let selected = match self.0.focused {
+            true => self.0.selected(),
+            false => &FormSelection::NoSelection,
+        };
+        match selected {
+            FormSelection::Active(a) if *a == i => FieldState::Active,
+            FormSelection::Hovered(h) if *h == i => FieldState::Hovered,
+            _ if !self.0.shows_problems(fb) => FieldState::Normal,
+            _ if self.0.is_invalid(fb) => FieldState::Invalid,
+            _ if self.0.warning(fb).is_some() => FieldState::Warning,
+            _ => FieldState::Normal,
+        }
+    }
 
-            let render_type = match (hovered, active, is_invalid) {
-                (_, true, _) => FieldRenderType::Active,
-                (true, false, _) => FieldRenderType::Hovered,
-                (false, false, true) => FieldRenderType::Invalid,
-                (false, false, false) => FieldRenderType::Normal,
-            };
-            self.render_field_gen(area[i], buf, field.value(), Some(field.name()), render_type);
-        });
+    /// Returns the error or warning message to show under `fb`, if any, along with its style.
+    fn problem(&self, fb: &'a FieldBuffer) -> Option<(&'a str, Style)> {
+        if !self.0.shows_problems(fb) {
+            return None;
+        }
+        if self.0.is_invalid(fb) {
+            return Some((self.0.validation_error(fb)?, self.0.invalid_field_style));
+        }
+        Some((self.0.warning(fb)?, self.0.warning_field_style))
     }
 
-    fn render_field_gen(
-        &self,
-        area: Rect,
-        buf: &mut Buffer,
-        content: &str,
-        title: Option<&str>,
-        fr: FieldRenderType,
-    ) {
-        match fr {
-            FieldRenderType::Normal => self.render_field(area, buf, content, title),
-            FieldRenderType::Invalid => self.render_field_invalid(area, buf, content, title),
-            FieldRenderType::Hovered => self.render_field_hovered(area, buf, content, title),
-            FieldRenderType::Active => self.render_field_active(area, buf, content, title),
+    /// Stores the areas of the fields just rendered, calling the form's
+    /// [`on_layout_change`](Form::on_layout_change) callback if they moved.
+    fn update_layout(&self, layout: Vec<(usize, Rect)>) {
+        {
+            let mut state = self.0.render_state.lock();
+            if state.layout == layout {
+                return;
+            }
+            state.layout.clone_from(&layout);
+        }
+        if let Some(on_layout_change) = &self.0.on_layout_change {
+            on_layout_change(&layout);
         }
     }
 
-    fn render_field(&self, area: Rect, buf: &mut Buffer, content: &str, title: Option<&str>) {
-        Paragraph::new(content)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_type(BorderType::Rounded)
-                    .title(match title {
-                        Some(t) => t,
-                        None => "",
-                    }),
-            )
-            .render(area, buf)
+    /// Returns the index of the first field to render so that the selected field fits in `height`
+    /// rows. The window only scrolls once the selection leaves it, or to fill it again once it
+    /// grows, so only the visible fields are ever laid out.
+    fn first_visible(&self, height: u16) -> usize {
+        let mut state = self.0.render_state.lock();
+        let Some(last) = self.0.fields.len().checked_sub(1) else {
+            state.first_visible = 0;
+            return 0;
+        };
+        let mut first = state.first_visible.min(last);
+
+        if let FormSelection::Hovered(s) | FormSelection::Active(s) = *self.0.selected() {
+            // the selection can be out of date if fields were removed
+            let s = s.min(last);
+            if s < first {
+                first = s;
+            } else {
+                // walk back from the selection until the window is full
+                let mut used = 0;
+                for (i, fb) in self.0.fields[first..=s].iter().enumerate().rev() {
+                    used += self.field_height(first + i, fb);
+                    if used > height {
+                        first += i + 1;
+                        break;
+                    }
+                }
+                first = first.min(s);
+            }
+        }
+
+        // scroll back up if the fields from `first` no longer fill the window, e.g. because the
+        // area grew
+        let mut used = 0;
+        for (i, fb) in self.0.fields.iter().enumerate().skip(first) {
+            used += self.field_height(i, fb);
+            if used > height {
+                break;
+            }
+        }
+        while first > 0 {
+            used += self.field_height(first - 1, &self.0.fields[first - 1]);
+            if used > height {
+                break;
+            }
+            first -= 1;
+        }
+
+        state.first_visible = first;
+        first
     }
 
-    fn render_field_hovered(
-        &self,
-        area: Rect,
-        buf: &mut Buffer,
-        content: &str,
-        title: Option<&str>,
-    ) {
-        Paragraph::new(content)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_style(self.0.hovered_field_style)
-                    .border_type(BorderType::Rounded)
-                    .title(match title {
-                        Some(t) => t,
-                        None => "",
-                    }),
-            )
-            .render(area, buf)
+    fn field_height(&self, i: usize, fb: &FieldBuffer) -> u16 {
+        let content = match (&fb.kind, self.0.selected()) {
+            (FieldKind::Select { options, .. }, FormSelection::Active(a))
+                if *a == i && self.0.select_display == SelectDisplay::Inline =>
+            {
+                options.len() as u16
+            }
+            (FieldKind::TextArea { rows }, _) => *rows,
+            (FieldKind::Custom(widget), _) => widget.height(),
+            _ => 1,
+        };
+        2 + self.0.padding.top + content + self.0.padding.bottom
     }
 
-    fn render_field_active(
+    /// Renders a single field without allocating. Returns the options to list under the field if
+    /// it has any open.
+    fn render_field(
         &self,
+        i: usize,
+        fb: &'a FieldBuffer,
         area: Rect,
         buf: &mut Buffer,
-        content: &str,
-        title: Option<&str>,
-    ) {
-        Paragraph::new(Line::from(vec![
-            Span::raw(content),
-            Span::styled(" ", Style::default().reversed()),
-        ]))
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(self.0.active_field_style)
-                .border_type(BorderType::Rounded)
-                .title_style(self.0.active_field_style)
-                .title(match title {
-                    Some(t) => t,
-                    None => "",
-                }),
-        )
-        .render(area, buf)
-    }
-
-    fn render_field_invalid(
+    ) -> Option<OptionList<'a>> {
+        let render_type = self.render_type(i, fb);
+        let (border_style, title_style) = match render_type {
+            FieldState::Normal => (self.0.default_field_style, Style::default()),
+            FieldState::Invalid => (self.0.invalid_field_style, self.0.invalid_field_style),
+            FieldState::Warning => (self.0.warning_field_style, self.0.warning_field_style),
+            FieldState::Hovered => (self.0.hovered_field_style, Style::default()),
+            FieldState::Active => (self.0.active_field_style, self.0.active_field_style),
+        };
+
+        // the layout doesn't depend on which borders are drawn, so labels and problems stay put
+        let inner = Block::default()
+            .borders(Borders::ALL)
+            .padding(self.0.padding)
+            .inner(area);
+        let (border_type, borders) = self.0.field_borders[render_type as usize];
+        Block::default()
+            .borders(borders)
+            .border_type(border_type)
+            .border_style(border_style)
+            .render(area, buf);
+        // problems are shown on the opposite border to the label
+        let position = fb.title_position.unwrap_or(self.0.title_position);
+        let (title_y, problem_y) = match position {
+            Position::Top => (area.y, area.bottom() - 1),
+            Position::Bottom => (area.bottom() - 1, area.y),
+        };
+        if area.width > 2 {
+            let label = self.label(&fb.name);
+            let width = area.width - 2;
+            let len = width.min(label.chars().count() as u16);
+            let offset = match fb.title_alignment.unwrap_or(self.0.title_alignment) {
+                Alignment::Left => 0,
+                Alignment::Center => (width - len) / 2,
+                Alignment::Right => width - len,
+            };
+            buf.set_stringn(
+                area.x + 1 + offset,
+                title_y,
+                label,
+                width as usize,
+                title_style,
+            );
+        }
+        if let (Some((problem, style)), true) = (self.problem(fb), area.width > 2) {
+            buf.set_stringn(
+                area.x + 1,
+                problem_y,
+                problem,
+                area.width as usize - 2,
+                style,
+            );
+        }
+        if inner.width == 0 || inner.height == 0 {
+            return None;
+        }
+
+        if let Some(render) = &fb.render {
+            render(fb.val.as_str(), render_type, inner, buf);
+            return None;
+        }
+        let active = matches!(render_type, FieldState::Active);
+        if let FieldKind::Custom(widget) = &fb.kind {
+            widget.render(inner, buf, active);
+            return None;
+        }
+        if let (
+            FieldKind::Select {
+                options,
+                highlighted,
+            },
+            true,
+        ) = (&fb.kind, active)
+        {
+            return match self.0.select_display {
+                SelectDisplay::Inline => {
+                    write_options(buf, inner, options, Some(*highlighted));
+                    None
+                }
+                SelectDisplay::Popup => {
+                    let (x, _) = write_str(buf, inner, fb.val.as_str(), Style::default());
+                    let rest = Rect {
+                        x: x + 1,
+                        width: inner.right().saturating_sub(x + 1),
+                        ..inner
+                    };
+                    write_str(buf, rest, "▾", Style::default());
+                    Some((options, Some(*highlighted)))
+                }
+            };
+        }
+
+        let composition = if active { &self.0.composition[..] } else { "" };
+        let style = TextStyle {
+            masked: fb.secret,
+            sanitize: self.0.sanitize,
+            selected: active && self.0.all_selected,
+            allowed: fb
+                .allowed_chars
+                .as_ref()
+                .map(|allowed| (allowed, self.0.invalid_char_style)),
+        };
+        let mut text_area = inner;
+        let mut suffix = "";
+        if let FieldKind::Number {
+            range,
+            suffix: s,
+            gauge,
+            ..
+        } = &fb.kind
+        {
+            if *gauge && inner.width >= 8 {
+                let width = inner.width / 2;
+                text_area.width -= width + 1;
+                let val = fb.val.as_str().parse().ok();
+                let gauge_area = Rect {
+                    x: inner.right() - width,
+                    width,
+                    ..inner
+                };
+                write_gauge(buf, gauge_area, val, range);
+            }
+            if text_area.width as usize > s.chars().count() {
+                suffix = s;
+                text_area.width -= s.chars().count() as u16;
+            }
+        }
+
+        let cursor = write_text(buf, text_area, &fb.val, composition, style);
+        if let (Some(placeholder), true) =
+            (&fb.placeholder, fb.val.is_empty() && composition.is_empty())
+        {
+            write_str(
+                buf,
+                text_area,
+                &self.label(placeholder),
+                Style::default().dim(),
+            );
+        }
+        if !suffix.is_empty() {
+            // the suffix follows the visible end of the value, and the cursor when it's past it
+            let width = |text: &str| text.chars().map(|ch| style.width(ch)).sum::<usize>();
+            let len = width(fb.val.as_str()) + width(composition);
+            let col = width(fb.val.before()) + width(composition);
+            let first_col = col.saturating_sub(text_area.width as usize - 1);
+            let mut x = text_area.x + (len - first_col).min(text_area.width as usize) as u16;
+            if let (Some((cursor_x, _)), true) = (cursor, active) {
+                x = x.max(cursor_x + 1);
+            }
+            let x = x.min(text_area.right());
+            buf.set_stringn(x, inner.y, suffix, suffix.len(), Style::default());
+        }
+        if !active {
+            return None;
+        }
+        if let Some((x, y)) = cursor {
+            buf.get_mut(x, y).set_style(Style::default().reversed());
+        }
+        (!self.0.suggestions.is_empty()).then_some((&self.0.suggestions[..], self.0.suggestion))
+    }
+
+    /// Renders `options` in a list floating below `field_area`, e.g. for select fields or
+    /// suggestions, with `title` on its border.
+    fn render_popup_list(
         &self,
-        area: Rect,
+        field_area: Rect,
         buf: &mut Buffer,
-        content: &str,
+        options: &[String],
+        highlighted: Option<usize>,
         title: Option<&str>,
     ) {
-        Paragraph::new(content)
+        let y = field_area.bottom().min(buf.area.bottom());
+        let height = (options.len() as u16 + 2).min(buf.area.bottom() - y);
+        let area = Rect::new(field_area.x, y, field_area.width, height);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(self.0.active_field_style)
+            .border_type(self.0.border_type)
+            .title(title.unwrap_or_default());
+        let inner = block.inner(area);
+
+        Clear.render(area, buf);
+        block.render(area, buf);
+        write_options(buf, inner, options, highlighted);
+    }
+
+    /// Renders a field's tooltip in a box below `field_area`, or above it if there isn't room
+    /// below.
+    fn render_tooltip(&self, field_area: Rect, buf: &mut Buffer, text: &str) {
+        let lines = wrap(
+            &self.label(text),
+            field_area.width.saturating_sub(2) as usize,
+        );
+        let height = lines.len() as u16 + 2;
+        let below = buf.area.bottom().saturating_sub(field_area.bottom());
+        let above = field_area.top().saturating_sub(buf.area.top());
+        let (y, height) = if below >= height || below >= above {
+            (field_area.bottom(), height.min(below))
+        } else {
+            let height = height.min(above);
+            (field_area.top() - height, height)
+        };
+        let area = Rect::new(field_area.x, y, field_area.width, height);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(self.0.hovered_field_style)
+            .border_type(self.0.border_type);
+        let inner = block.inner(area);
+
+        Clear.render(area, buf);
+        block.render(area, buf);
+        for (line, y) in lines.iter().zip(inner.top()..inner.bottom()) {
+            write_str(buf, Rect { y, ..inner }, line, Style::default());
+        }
+    }
+
+    fn render_hints(&self, area: Rect, buf: &mut Buffer) {
+        let (bindings, extra) = match self.0.selected() {
+            FormSelection::Active(_) => (self.0.keymap.editing(), &[][..]),
+            _ => (self.0.keymap.navigation(), &self.0.hints[..]),
+        };
+
+        let mut groups: Vec<(Vec<String>, &str)> = Vec::new();
+        for (key, action) in bindings {
+            match groups.iter_mut().find(|(_, hint)| *hint == action.hint()) {
+                Some((keys, _)) => keys.push(key_label(*key)),
+                None => groups.push((vec![key_label(*key)], action.hint())),
+            }
+        }
+
+        let hints = groups
+            .iter()
+            .map(|(keys, hint)| (keys.join("/"), self.label(hint)))
+            .chain(
+                extra
+                    .iter()
+                    .map(|(key, desc)| (key.clone(), Cow::Borrowed(desc.as_str()))),
+            );
+
+        let mut spans = Vec::new();
+        for (key, desc) in hints {
+            if !spans.is_empty() {
+                spans.push(Span::raw(" • ").dim());
+            }
+            spans.push(Span::styled(key, self.0.hovered_field_style));
+            spans.push(Span::raw(format!(": {desc}")).dim());
+        }
+
+        Paragraph::new(Line::from(spans)).render(area, buf)
+    }
+
+    fn render_help(&self, area: Rect, buf: &mut Buffer) {
+        let keymap = &self.0.keymap;
+        let labelled = |bindings: &[(KeyCode, Action)]| {
+            bindings
+                .iter()
+                .map(|(key, action)| (key_label(*key), *action))
+                .collect::<Vec<_>>()
+        };
+        let chords = keymap
+            .chords()
+            .iter()
+            .map(|(modifiers, key, action)| (chord_label(*modifiers, *key), *action));
+        let sections = [
+            ("Navigation", labelled(keymap.navigation())),
+            (
+                "Editing",
+                labelled(keymap.editing())
+                    .into_iter()
+                    .chain(chords)
+                    .collect(),
+            ),
+        ];
+
+        let mut lines = Vec::new();
+        for (title, bindings) in sections {
+            if bindings.is_empty() {
+                continue;
+            }
+            if !lines.is_empty() {
+                lines.push(Line::default());
+            }
+            lines.push(Line::styled(self.label(title), Style::default().bold()));
+            for (key, action) in bindings {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("{key:<12}"), self.0.hovered_field_style),
+                    Span::raw(self.label(action.description())),
+                ]));
+            }
+        }
+
+        let width = lines.iter().map(Line::width).max().unwrap_or_default() as u16 + 4;
+        let height = lines.len() as u16 + 2;
+        let area = centered_rect(width, height, area);
+
+        Clear.render(area, buf);
+        Paragraph::new(lines)
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .border_style(self.0.invalid_field_style)
-                    .border_type(BorderType::Rounded)
-                    .title_style(self.0.invalid_field_style)
-                    .title(match title {
-                        Some(t) => t,
-                        None => "",
-                    }),
+                    .border_type(self.0.border_type)
+                    .padding(Padding::horizontal(1))
+                    .title(Span::raw(self.label("Help"))),
             )
             .render(area, buf)
     }
 }
+
+/// Writes `text` on the first row of `area`, truncated to its width. Returns the position after
+/// the last char written.
+fn write_str(buf: &mut Buffer, area: Rect, text: &str, style: Style) -> (u16, u16) {
+    buf.set_stringn(area.x, area.y, text, area.width as usize, style)
+}
+
+/// How the text of a field is displayed.
+#[derive(Clone, Copy)]
+pub(crate) struct TextStyle<'a> {
+    /// Show every char as `•`.
+    pub(crate) masked: bool,
+    /// Show control chars as visible placeholders, see [`sanitize`].
+    pub(crate) sanitize: bool,
+    /// Highlight the whole text as selected.
+    pub(crate) selected: bool,
+    /// Chars allowed in the text, and the style of any others.
+    pub(crate) allowed: Option<(&'a CharFilter, Style)>,
+}
+
+impl<'a> TextStyle<'a> {
+    /// Style of the text.
+    pub(crate) fn text(&self) -> Style {
+        match self.selected {
+            true => Style::default().reversed(),
+            false => Style::default(),
+        }
+    }
+
+    /// Style of `ch` in the text, highlighting it if it isn't allowed.
+    pub(crate) fn char(&self, ch: char) -> Style {
+        match self.allowed {
+            Some((allowed, style)) if !self.masked && !allowed(ch) => self.text().patch(style),
+            _ => self.text(),
+        }
+    }
+
+    /// Returns the char to display for `ch`.
+    pub(crate) fn display(&self, ch: char) -> char {
+        match ch {
+            _ if self.masked => '•',
+            ch if self.sanitize => sanitize(ch),
+            ch => ch,
+        }
+    }
+
+    /// Number of cells `ch` takes up when displayed, e.g. 2 for most CJK chars.
+    fn width(&self, ch: char) -> usize {
+        self.display(ch).width().unwrap_or(0)
+    }
+}
+
+/// Returns a visible placeholder for control chars, e.g. `␛` for the escape that starts an ANSI
+/// escape sequence, which would otherwise corrupt the terminal.
+pub(crate) fn sanitize(ch: char) -> char {
+    match ch {
+        '\0'..='\x1f' => char::from_u32(0x2400 + ch as u32).unwrap_or(ch),
+        '\x7f' => '␡',
+        '\u{80}'..='\u{9f}' => '�',
+        ch => ch,
+    }
+}
+
+/// Writes `text` into `area`, one line per row, displayed according to `style`. An IME
+/// `composition` in progress is written underlined at the cursor. The text is scrolled so the
+/// cursor is inside `area`, counting the cells each char takes up rather than chars so wide chars
+/// don't push it out. Returns the position of the cursor.
+///
+/// With the `bidi` feature enabled, text containing right-to-left chars is reordered for display.
+pub(crate) fn write_text(
+    buf: &mut Buffer,
+    area: Rect,
+    text: &TextBuffer,
+    composition: &str,
+    style: TextStyle<'_>,
+) -> Option<(u16, u16)> {
+    #[cfg(feature = "bidi")]
+    if !style.masked && composition.is_empty() && crate::bidi::has_rtl(text) {
+        return crate::bidi::write_text(buf, area, text, style);
+    }
+
+    let before = text.before();
+    let line = before.matches('\n').count();
+    let col = before
+        .chars()
+        .rev()
+        .take_while(|ch| *ch != '\n')
+        .map(|ch| style.width(ch))
+        .sum::<usize>()
+        + composition.chars().map(|ch| style.width(ch)).sum::<usize>();
+
+    let mut writer = TextWriter {
+        area,
+        first_line: line.saturating_sub(area.height as usize - 1),
+        first_col: col.saturating_sub(area.width as usize - 1),
+        style,
+        line: 0,
+        col: 0,
+        x: area.x,
+    };
+    for ch in before.chars() {
+        writer.write(buf, ch, style.char(ch));
+    }
+    for ch in composition.chars() {
+        writer.write(buf, ch, Style::default().underlined());
+    }
+    let cursor = writer.position();
+    for ch in text.after() {
+        writer.write(buf, ch, style.char(ch));
+    }
+    cursor
+}
+
+/// Writes text into an area char by char, skipping lines and columns scrolled out of view.
+struct TextWriter<'a> {
+    area: Rect,
+    first_line: usize,
+    /// First column shown, in cells. Wide chars starting left of it aren't shown.
+    first_col: usize,
+    style: TextStyle<'a>,
+    line: usize,
+    col: usize,
+    x: u16,
+}
+
+impl<'a> TextWriter<'a> {
+    fn write(&mut self, buf: &mut Buffer, ch: char, style: Style) {
+        if ch == '\n' {
+            self.line += 1;
+            self.col = 0;
+            self.x = self.area.x;
+            return;
+        }
+        if let (Some((x, y)), true) = (self.position(), self.col >= self.first_col) {
+            let ch = self.style.display(ch);
+            let width = (self.area.right() - x) as usize;
+            self.x = buf
+                .set_stringn(x, y, ch.encode_utf8(&mut [0; 4]), width, style)
+                .0;
+        }
+        self.col += self.style.width(ch);
+    }
+
+    /// Position of the next char, if it's inside the area.
+    fn position(&self) -> Option<(u16, u16)> {
+        let row = self.line.checked_sub(self.first_line)?;
+        (row < self.area.height as usize && self.x < self.area.right())
+            .then(|| (self.x, self.area.y + row as u16))
+    }
+}
+
+/// Splits `text` into lines of at most `width` chars, breaking between words where possible.
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    if width == 0 {
+        return lines;
+    }
+    for paragraph in text.split('\n') {
+        let mut line = String::new();
+        let mut len = 0;
+        for word in paragraph.split_whitespace() {
+            let word_len = word.chars().count();
+            if len > 0 && len + 1 + word_len > width {
+                lines.push(std::mem::take(&mut line));
+                len = 0;
+            }
+            if len > 0 {
+                line.push(' ');
+                len += 1;
+            }
+            // words longer than a line are broken anywhere
+            for ch in word.chars() {
+                if len == width {
+                    lines.push(std::mem::take(&mut line));
+                    len = 0;
+                }
+                line.push(ch);
+                len += 1;
+            }
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+/// Writes a bar filling `area` according to where `val` is in `range`, or an empty bar if there's
+/// no value.
+fn write_gauge(
+    buf: &mut Buffer,
+    area: Rect,
+    val: Option<i64>,
+    range: &std::ops::RangeInclusive<i64>,
+) {
+    let (start, end) = (*range.start() as i128, *range.end() as i128);
+    let filled = match val {
+        Some(val) if end > start => {
+            let val = (val as i128).clamp(start, end);
+            ((val - start) * area.width as i128 + (end - start) / 2) / (end - start)
+        }
+        _ => 0,
+    } as u16;
+    for x in area.left()..area.right() {
+        let symbol = if x - area.left() < filled {
+            "█"
+        } else {
+            "░"
+        };
+        buf.get_mut(x, area.y).set_symbol(symbol);
+    }
+}
+
+/// Writes one option per row of `area`, reversing the style of the highlighted option. Options
+/// are scrolled so the highlighted one is inside `area`.
+fn write_options(buf: &mut Buffer, area: Rect, options: &[String], highlighted: Option<usize>) {
+    let first = highlighted.map_or(0, |i| (i + 1).saturating_sub(area.height as usize));
+    let rows = options.iter().enumerate().skip(first);
+    for ((i, option), y) in rows.zip(area.top()..area.bottom()) {
+        let style = if Some(i) == highlighted {
+            Style::default().reversed()
+        } else {
+            Style::default()
+        };
+        write_str(buf, Rect { y, ..area }, option, style);
+    }
+}
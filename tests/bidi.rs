@@ -0,0 +1,51 @@
+#![cfg(feature = "bidi")]
+
+use crossterm::event::KeyCode;
+use ratatui::style::{Style, Stylize};
+use tui_form_widget::test_utils::{self, FormTester};
+use tui_form_widget::{FieldDef, Form, FormSelection};
+
+fn editing(field: FieldDef) -> FormTester {
+    let mut form = Form::from(vec![field]);
+    form.select(FormSelection::Active(0));
+    FormTester::new(form)
+}
+
+#[test]
+fn right_to_left_text_is_reordered_and_right_aligned() {
+    let tester = editing(FieldDef::text("Name")).type_str("שלום");
+    let buf = test_utils::render(tester.form(), 12, 3);
+    assert_eq!(test_utils::lines(&buf)[1], "│      םולש│");
+    assert_eq!(tester.form().value(0), Some("שלום"));
+}
+
+#[test]
+fn runs_of_each_direction_keep_their_order() {
+    let form = Form::from(vec![FieldDef::text("Name").value("abc שלום")]);
+    let buf = test_utils::render(&form, 12, 3);
+    assert_eq!(test_utils::lines(&buf)[1], "│abc םולש  │");
+
+    let form = Form::from(vec![FieldDef::text("Name").value("שלום abc")]);
+    let buf = test_utils::render(&form, 12, 3);
+    assert_eq!(test_utils::lines(&buf)[1], "│  abc םולש│");
+}
+
+#[test]
+fn the_cursor_follows_the_visual_order() {
+    let cursor = Style::default().reversed();
+    // at the logical end of right-to-left text, the cursor is left of it
+    let tester = editing(FieldDef::text("Name").value("שלום"));
+    test_utils::assert_style(&test_utils::render(tester.form(), 12, 3), 6, 1, cursor);
+
+    // and at the logical start, on its rightmost char
+    let tester = tester.key(KeyCode::Home);
+    test_utils::assert_style(&test_utils::render(tester.form(), 12, 3), 10, 1, cursor);
+    tester.type_str("א").assert_value(0, "אשלום");
+}
+
+#[test]
+fn masked_text_is_not_reordered() {
+    let form = Form::from(vec![FieldDef::text("Pin").value("שלום").secret()]);
+    let buf = test_utils::render(&form, 12, 3);
+    assert_eq!(test_utils::lines(&buf)[1], "│••••      │");
+}
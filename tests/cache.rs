@@ -0,0 +1,181 @@
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use crossterm::event::KeyCode;
+use ratatui::prelude::*;
+use ratatui::widgets::Widget;
+use tui_form_widget::test_utils;
+use tui_form_widget::{
+    FieldDef, FieldState, FieldWidget, Focusable, Form, FormEvent, FormSelection, KeyValues,
+    StringList, Tags,
+};
+
+/// Draws `background` on the last row, then the form over the whole area, like an application
+/// drawing a status line underneath the form.
+fn frame(form: &Form, background: &str) -> Buffer {
+    let area = Rect::new(0, 0, 20, 8);
+    let mut buf = Buffer::empty(area);
+    buf.set_string(0, 7, background, Style::default().red());
+    form.widget().render(area, &mut buf);
+    buf
+}
+
+#[test]
+fn what_is_drawn_underneath_shows_through() {
+    let form = Form::from(vec!["Name", "Email"]);
+    assert_eq!(
+        test_utils::lines(&frame(&form, "one"))[7],
+        "one                 "
+    );
+
+    let buf = frame(&form, "two");
+    assert_eq!(test_utils::lines(&buf)[7], "two                 ");
+    test_utils::assert_style(&buf, 0, 7, Style::default().red());
+}
+
+#[test]
+fn styles_are_patched_onto_what_is_underneath() {
+    let mut form = Form::from(vec!["Name", "Email"]);
+    form.set_focus(false);
+    frame(&form, "one");
+
+    let buf = frame(&form, "two");
+    assert_eq!(test_utils::lines(&buf)[7], "two                 ");
+    test_utils::assert_style(&buf, 0, 7, Style::default().red().dim());
+}
+
+#[test]
+fn changes_to_the_form_are_drawn() {
+    let mut form = Form::from(vec!["Name", "Email"]);
+    form.select(FormSelection::Active(0));
+    frame(&form, "");
+
+    form.type_str("ferris");
+    assert_eq!(
+        test_utils::lines(&frame(&form, ""))[1],
+        "│ferris            │"
+    );
+    form.select(FormSelection::Active(1));
+    form.type_str("f@rust");
+    assert_eq!(
+        test_utils::lines(&frame(&form, ""))[4],
+        "│f@rust            │"
+    );
+
+    // a different area isn't drawn from the last frame
+    let buf = test_utils::render(&form, 12, 6);
+    assert_eq!(test_utils::lines(&buf)[4], "│f@rust    │");
+}
+
+#[test]
+fn callbacks_are_drawn_every_frame() {
+    let french = Arc::new(AtomicBool::new(false));
+    let mut form = Form::from(vec![FieldDef::text("Name").render_with({
+        let french = french.clone();
+        move |_, _: FieldState, area, buf| {
+            let text = if french.load(Ordering::Relaxed) {
+                "oui"
+            } else {
+                "yes"
+            };
+            buf.set_string(area.x, area.y, text, Style::default());
+        }
+    })]);
+    form.labels({
+        let french = french.clone();
+        move |key: &str| (key == "Name" && french.load(Ordering::Relaxed)).then(|| "Nom".into())
+    });
+    assert_eq!(
+        test_utils::lines(&frame(&form, ""))[..2],
+        ["╭Name──────────────╮", "│yes               │"]
+    );
+
+    french.store(true, Ordering::Relaxed);
+    assert_eq!(
+        test_utils::lines(&frame(&form, ""))[..2],
+        ["╭Nom───────────────╮", "│oui               │"]
+    );
+}
+
+#[test]
+fn cached_frames_match_fresh_ones() {
+    let mut form = Form::from(vec![
+        FieldDef::text("Name").validator(|val| !val.is_empty()),
+        FieldDef::text("Confirm").linked_validator("Name", |val, name| val == name),
+        FieldDef::select("Size", &["S", "M", "L"]),
+        FieldDef::number("Count").value("3"),
+        FieldDef::textarea("Notes", 2),
+        FieldDef::string_list("Hosts", StringList::new(["a"])),
+        FieldDef::tags("Tags", Tags::default()),
+        FieldDef::key_values("Env", KeyValues::default()),
+        FieldDef::text("Host").suggestions(|val: &str| vec![format!("{val}.example.com")]),
+    ]);
+    form.show_hints(true);
+    let keys = "jxjjkky\nab\u{2190}\u{2190}c\u{2192}\x1b\x08j\nab\x08\x1bj\njj\n\x1bj\n\u{2191}\u{2191}\u{2193}\x1b\
+                j\nl1\nx\x1b\x1bj\nq,\nr,\x08\x1bj\nk=v\x1bj\nwww\tk\x1bjjjjjjjjjk";
+    for (step, ch) in keys.chars().enumerate() {
+        let key = match ch {
+            '\n' => KeyCode::Enter,
+            '\x1b' => KeyCode::Esc,
+            '\x08' => KeyCode::Backspace,
+            '\t' => KeyCode::Tab,
+            '\u{2191}' => KeyCode::Up,
+            '\u{2193}' => KeyCode::Down,
+            '\u{2190}' => KeyCode::Left,
+            '\u{2192}' => KeyCode::Right,
+            ch => KeyCode::Char(ch),
+        };
+        form.input(key);
+        let cached = test_utils::render(&form, 24, 16);
+        let mut fresh = form.clone();
+        fresh.set_focus(true);
+        let expected = test_utils::render(&fresh, 24, 16);
+        assert_eq!(cached, expected, "frames differ after key {step} ({key:?})");
+    }
+}
+
+/// Counts how often it's drawn.
+#[derive(Clone, Default)]
+struct Counted(Arc<AtomicUsize>);
+
+impl FieldWidget for Counted {
+    fn handle_input(&mut self, _key: KeyCode) -> FormEvent {
+        FormEvent::Ignored
+    }
+
+    fn render(&self, _area: Rect, _buf: &mut Buffer, _active: bool) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn value(&self) -> String {
+        String::new()
+    }
+}
+
+#[test]
+fn only_fields_that_changed_are_drawn_again() {
+    let counted = Counted::default();
+    let draws = counted.0.clone();
+    let mut form = Form::from(vec![
+        FieldDef::custom("Counted", counted),
+        FieldDef::text("Name"),
+    ]);
+    frame(&form, "");
+    assert_eq!(draws.load(Ordering::Relaxed), 1);
+
+    // ignored keys change nothing
+    form.input(KeyCode::F(5));
+    frame(&form, "");
+    assert_eq!(draws.load(Ordering::Relaxed), 1);
+
+    // neither does editing another field
+    form.select(FormSelection::Active(1));
+    form.type_str("ferris");
+    form.input(KeyCode::Left);
+    frame(&form, "");
+    assert_eq!(draws.load(Ordering::Relaxed), 1);
+
+    form.select(FormSelection::Hovered(0));
+    frame(&form, "");
+    assert_eq!(draws.load(Ordering::Relaxed), 2);
+}
@@ -0,0 +1,128 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::style::{Style, Stylize};
+use tui_form_widget::test_utils::{self, FormTester};
+use tui_form_widget::{FieldDef, Form, FormSelection};
+
+fn editing(field: FieldDef) -> FormTester {
+    let mut form = Form::from(vec![field]);
+    form.select(FormSelection::Active(0));
+    FormTester::new(form)
+}
+
+fn chord(tester: FormTester, modifiers: KeyModifiers, key: KeyCode) -> FormTester {
+    let mut form = tester.into_form();
+    form.input_event(KeyEvent::new(key, modifiers));
+    FormTester::new(form)
+}
+
+#[test]
+fn the_cursor_moves_over_multibyte_chars() {
+    editing(FieldDef::text("Name").value("日本語"))
+        .key(KeyCode::Left)
+        .type_str("é")
+        .assert_value(0, "日本é語")
+        .key(KeyCode::Left)
+        .key(KeyCode::Left)
+        .type_str("🦀")
+        .assert_value(0, "日🦀本é語")
+        .key(KeyCode::Right)
+        .key(KeyCode::Right)
+        .backspace()
+        .assert_value(0, "日🦀本語")
+        .key(KeyCode::Delete)
+        .assert_value(0, "日🦀本");
+}
+
+#[test]
+fn edits_at_both_ends() {
+    editing(FieldDef::text("Name").value("bc"))
+        .key(KeyCode::Home)
+        .type_str("a")
+        .assert_value(0, "abc")
+        .backspace()
+        .backspace()
+        .assert_value(0, "bc")
+        .key(KeyCode::Delete)
+        .assert_value(0, "c")
+        .key(KeyCode::End)
+        .type_str("d")
+        .assert_value(0, "cd")
+        .key(KeyCode::Delete)
+        .backspace()
+        .assert_value(0, "c")
+        .key(KeyCode::Right)
+        .backspace()
+        .backspace()
+        .assert_value(0, "");
+}
+
+#[test]
+fn up_and_down_keep_the_column() {
+    editing(FieldDef::textarea("Notes", 3).value("abc\nde\nfghij"))
+        // the end of a shorter line stands in for the column
+        .key(KeyCode::Up)
+        .type_str("1")
+        .assert_value(0, "abc\nde1\nfghij")
+        .key(KeyCode::Up)
+        .type_str("2")
+        .assert_value(0, "abc2\nde1\nfghij")
+        .key(KeyCode::Down)
+        .key(KeyCode::Down)
+        .type_str("3")
+        .assert_value(0, "abc2\nde1\nfgh3ij")
+        // moving down from the last line goes to its end
+        .key(KeyCode::Down)
+        .type_str("4")
+        .assert_value(0, "abc2\nde1\nfgh3ij4")
+        .key(KeyCode::Home)
+        .key(KeyCode::Up)
+        .key(KeyCode::Up)
+        .key(KeyCode::Up)
+        .type_str("5")
+        .assert_value(0, "5abc2\nde1\nfgh3ij4");
+}
+
+#[test]
+fn word_movement() {
+    let tester = editing(FieldDef::text("Cmd").value("git  commit --amend"));
+    let tester = chord(tester, KeyModifiers::CONTROL, KeyCode::Left).type_str("1");
+    let tester = chord(tester, KeyModifiers::ALT, KeyCode::Char('b'));
+    let tester = chord(tester, KeyModifiers::ALT, KeyCode::Char('b')).type_str("2");
+    let tester = tester.assert_value(0, "git  2commit --1amend");
+
+    let tester = chord(tester, KeyModifiers::CONTROL, KeyCode::Right).type_str("3");
+    let tester = chord(tester, KeyModifiers::ALT, KeyCode::Char('f'));
+    let tester = chord(tester, KeyModifiers::ALT, KeyCode::Char('f')).type_str("4");
+    tester.assert_value(0, "git  2commit3 --1amend4");
+}
+
+#[test]
+fn the_value_follows_edits_in_the_middle() {
+    let mut form = editing(FieldDef::text("Name").value("ferris"))
+        .key(KeyCode::Left)
+        .key(KeyCode::Left)
+        .into_form();
+    // reading with the cursor mid-text builds a copy, which each edit must replace
+    assert_eq!(form.value(0), Some("ferris"));
+    form.type_str("-");
+    assert_eq!(form.value(0), Some("ferr-is"));
+    form.input(KeyCode::Delete);
+    assert_eq!(form.value(0), Some("ferr-s"));
+    form.input(KeyCode::Backspace);
+    assert_eq!(form.value(0), Some("ferrs"));
+    form.input(KeyCode::Left);
+    assert_eq!(form.value(0), Some("ferrs"));
+    form.input(KeyCode::End);
+    assert_eq!(form.value(0), Some("ferrs"));
+}
+
+#[test]
+fn wide_chars_scroll_by_the_cells_they_take_up() {
+    let mut form = editing(FieldDef::text("Name").value("日本語日本語")).into_form();
+    form.set_composition("にほ");
+    let buf = test_utils::render(&form, 12, 3);
+    assert_eq!(test_utils::lines(&buf)[1], "│本 語 に ほ   │");
+    // the cursor follows the composition, inside the field
+    test_utils::assert_style(&buf, 9, 1, Style::default().reversed());
+    test_utils::assert_style(&buf, 5, 1, Style::default().underlined());
+}
@@ -0,0 +1,89 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use tui_form_widget::test_utils::FormTester;
+use tui_form_widget::{FieldDef, Form, FormHistory, FormSelection};
+
+/// Returns a form with one field remembering up to 3 values, after submitting each of `values`.
+fn submitted(values: &[&str]) -> Form {
+    let mut form = Form::from(vec![FieldDef::text("Host").history(3)]);
+    for val in values {
+        form.select(FormSelection::Active(0));
+        form.input_event(KeyEvent::new(KeyCode::Char('l'), KeyModifiers::CONTROL));
+        form.type_str(val);
+        form.submit();
+    }
+    form.input_event(KeyEvent::new(KeyCode::Char('l'), KeyModifiers::CONTROL));
+    form
+}
+
+#[test]
+fn up_and_down_cycle_through_submitted_values() {
+    FormTester::new(submitted(&["alpha", "beta"]))
+        .assert_value("Host", "")
+        .key(KeyCode::Up)
+        .assert_value("Host", "beta")
+        .key(KeyCode::Up)
+        .assert_value("Host", "alpha")
+        .key(KeyCode::Up)
+        .assert_value("Host", "alpha")
+        .key(KeyCode::Down)
+        .assert_value("Host", "beta")
+        .key(KeyCode::Down)
+        .assert_value("Host", "");
+}
+
+#[test]
+fn history_is_only_recalled_in_empty_fields() {
+    FormTester::new(submitted(&["alpha"]))
+        .type_str("b")
+        .key(KeyCode::Up)
+        .assert_value("Host", "b");
+}
+
+#[test]
+fn history_keeps_the_latest_values_up_to_the_limit() {
+    let form = submitted(&["a", "b", "b", "c", "d"]);
+    assert_eq!(form.history().fields["Host"], ["b", "c", "d"]);
+}
+
+#[test]
+fn invalid_submissions_and_secret_fields_are_not_recorded() {
+    let mut form = Form::from(vec![
+        FieldDef::text("User").history(3),
+        FieldDef::text("Password").secret().history(3),
+        FieldDef::text("Required").history(3),
+    ]);
+    form.select(FormSelection::Active(0));
+    form.type_str("ferris\nhunter2");
+    form.submit();
+    assert!(form.history().fields.values().all(Vec::is_empty));
+
+    form.select(FormSelection::Active(2));
+    form.type_str("yes");
+    form.submit();
+    let history = form.history();
+    assert_eq!(history.fields["User"], ["ferris"]);
+    assert!(history
+        .fields
+        .get("Password")
+        .into_iter()
+        .all(Vec::is_empty));
+}
+
+#[test]
+fn loaded_history_can_be_recalled() {
+    let mut history = FormHistory::default();
+    history
+        .fields
+        .insert("Host".to_string(), vec!["saved".to_string()]);
+    history
+        .fields
+        .insert("Unknown".to_string(), vec!["ignored".to_string()]);
+    let mut form = Form::from(vec![FieldDef::text("Host").history(3)]);
+    form.load_history(history);
+    assert!(!form.history().fields.contains_key("Unknown"));
+
+    form.focus_first();
+    FormTester::new(form)
+        .key(KeyCode::Up)
+        .assert_value("Host", "saved");
+}
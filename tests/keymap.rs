@@ -0,0 +1,74 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use tui_form_widget::test_utils::{self, FormTester};
+use tui_form_widget::{Action, Form, FormEvent, FormSelection, KeyMap};
+
+/// Renders `form` and returns the whole buffer as text.
+fn rendered(form: &Form) -> String {
+    test_utils::lines(&test_utils::render(form, 44, 40)).join("\n")
+}
+
+#[test]
+fn question_mark_toggles_the_help_overlay() {
+    let tester = FormTester::new(Form::from(vec!["Name", "Email"])).keys("?");
+    let text = rendered(tester.form());
+    assert!(text.contains("╭Help"), "{text}");
+    assert!(text.contains("j           Next field"), "{text}");
+    assert!(text.contains("Ctrl+a      Select all"), "{text}");
+
+    let tester = tester.keys("?");
+    assert!(!rendered(tester.form()).contains("Help"));
+}
+
+#[test]
+fn help_lists_custom_bindings() {
+    let mut keymap = KeyMap::default();
+    keymap.bind_navigation(KeyCode::Char('n'), Action::NextField);
+    keymap.bind_chord(
+        KeyModifiers::CONTROL,
+        KeyCode::Char('u'),
+        Action::ClearField,
+    );
+    let mut form = Form::from(vec!["Name"]);
+    form.keymap(keymap);
+    form.show_help(true);
+
+    let text = rendered(&form);
+    assert!(text.contains("n           Next field"), "{text}");
+    assert!(text.contains("Ctrl+u      Clear field"), "{text}");
+}
+
+#[test]
+fn custom_bindings_drive_the_form() {
+    let mut keymap = KeyMap::default();
+    keymap.bind_navigation(KeyCode::Char('n'), Action::NextField);
+    keymap.bind_editing(KeyCode::Tab, Action::NextField);
+    let mut form = Form::from(vec!["Name", "Email"]);
+    form.keymap(keymap);
+
+    FormTester::new(form)
+        .keys("n")
+        .assert_selected(FormSelection::Hovered(0))
+        .enter()
+        .key(KeyCode::Tab)
+        .assert_selected(FormSelection::Active(1));
+}
+
+#[test]
+fn unbound_keys_are_ignored() {
+    let mut form = Form::from(vec!["Name"]);
+    form.keymap(KeyMap::empty());
+    FormTester::new(form)
+        .keys("j")
+        .assert_event(FormEvent::Ignored)
+        .assert_selected(FormSelection::NoSelection);
+}
+
+#[test]
+fn chords_run_their_action() {
+    let mut form = Form::from(vec![("Name", "Ferris")]);
+    form.focus_first();
+    form.input_event(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL));
+    FormTester::new(form)
+        .type_str("Crab")
+        .assert_value("Name", "Crab");
+}
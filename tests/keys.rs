@@ -0,0 +1,50 @@
+use tui_form_widget::test_utils::FormTester;
+use tui_form_widget::{FieldDef, FieldKey, Form, FormSelection};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum FieldId {
+    Host,
+    Port,
+    User,
+}
+
+impl FieldKey for FieldId {}
+
+fn form() -> Form<FieldId> {
+    Form::from(vec![
+        FieldDef::text("Host").value("localhost").key(FieldId::Host),
+        FieldDef::number("Port").value("8080").key(FieldId::Port),
+        FieldDef::text("User").key(FieldId::User),
+    ])
+}
+
+#[test]
+fn fields_are_looked_up_by_key() {
+    let mut form = form();
+    form.focus(FieldId::User).unwrap();
+    FormTester::new(form)
+        .keys("ferris")
+        .assert_value(FieldId::User, "ferris")
+        .assert_value(FieldId::Host, "localhost")
+        .assert_selected(FormSelection::Active(2));
+}
+
+#[test]
+fn keys_follow_their_fields() {
+    let mut form = form();
+    form.move_field(FieldId::Host, 2).unwrap();
+    form.insert_field(
+        0,
+        FieldDef::text("Login").value("ferris").key(FieldId::User),
+    );
+    assert_eq!(form.remove_field(FieldId::Port).unwrap(), "8080");
+    assert_eq!(form.value(FieldId::Host), Some("localhost"));
+    assert_eq!(form.key(2), Some(&FieldId::Host));
+    // the first field with a key is the one looked up
+    assert_eq!(form.value(FieldId::User), Some("ferris"));
+
+    let submitted = form.into_values();
+    let keys: Vec<_> = submitted.iter().map(|field| *field.key()).collect();
+    assert_eq!(keys, [FieldId::User, FieldId::User, FieldId::Host]);
+    assert_eq!(submitted[0].name(), "Login");
+}
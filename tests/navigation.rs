@@ -0,0 +1,61 @@
+use crossterm::event::KeyCode;
+use tui_form_widget::test_utils::FormTester;
+use tui_form_widget::{Form, FormEvent, FormSelection, WrapBehavior};
+
+fn form(wrap: WrapBehavior) -> Form {
+    let mut form = Form::from(vec!["Name", "Email", "Phone"]);
+    form.wrap_behavior(wrap);
+    form.select(FormSelection::Hovered(0));
+    form
+}
+
+#[test]
+fn wraps_around_by_default() {
+    FormTester::new(Form::from(vec!["Name", "Email"]))
+        .key(KeyCode::Char('j'))
+        .assert_selected(FormSelection::Hovered(0))
+        .keys("k")
+        .assert_event(FormEvent::Handled)
+        .assert_selected(FormSelection::Hovered(1))
+        .keys("j")
+        .assert_selected(FormSelection::Hovered(0));
+}
+
+#[test]
+fn wrap_moves_past_either_end() {
+    FormTester::new(form(WrapBehavior::Wrap))
+        .keys("k")
+        .assert_selected(FormSelection::Hovered(2))
+        .keys("j")
+        .assert_selected(FormSelection::Hovered(0));
+}
+
+#[test]
+fn stop_stays_on_the_first_field() {
+    FormTester::new(form(WrapBehavior::Stop))
+        .keys("k")
+        .assert_event(FormEvent::ReachedStart)
+        .assert_selected(FormSelection::Hovered(0));
+}
+
+#[test]
+fn stop_stays_on_the_last_field() {
+    FormTester::new(form(WrapBehavior::Stop))
+        .keys("jj")
+        .assert_event(FormEvent::Handled)
+        .assert_selected(FormSelection::Hovered(2))
+        .keys("j")
+        .assert_event(FormEvent::ReachedEnd)
+        .assert_selected(FormSelection::Hovered(2));
+}
+
+#[test]
+fn stop_applies_to_active_fields() {
+    FormTester::new(form(WrapBehavior::Stop))
+        .keys("jj")
+        .enter()
+        .assert_selected(FormSelection::Active(2))
+        .enter()
+        .assert_event(FormEvent::ReachedEnd)
+        .assert_selected(FormSelection::Active(2));
+}
@@ -0,0 +1,35 @@
+use tui_form_widget::test_utils::{self, FormTester};
+use tui_form_widget::{FieldDef, Form, FormSelection, StringList};
+
+#[test]
+fn control_chars_are_shown_as_placeholders() {
+    let mut form = Form::from(vec!["Name"]);
+    form.select(FormSelection::Active(0));
+    let tester = FormTester::new(form).type_str("\x1b[31mred\x07\x7f");
+    let buf = test_utils::render(tester.form(), 14, 3);
+    assert_eq!(test_utils::lines(&buf)[1], "│␛[31mred␇␡  │");
+    // only the display is changed
+    tester.assert_value(0, "\x1b[31mred\x07\x7f");
+}
+
+#[test]
+fn placeholders_are_used_in_every_kind_of_field() {
+    let form = Form::from(vec![
+        FieldDef::textarea("Notes", 2).value("a\tb\nc\rd"),
+        FieldDef::string_list("Hosts", StringList::new(["x\x1by"])),
+    ]);
+    let buf = test_utils::render(&form, 12, 7);
+    assert_eq!(
+        test_utils::lines(&buf)[1..3],
+        ["│a␉b       │", "│c␍d       │"]
+    );
+    assert_eq!(test_utils::lines(&buf)[5], "│• x␛y     │");
+}
+
+#[test]
+fn sanitizing_can_be_turned_off() {
+    let mut form = Form::from(vec![FieldDef::text("Name").value("a\x1bb")]);
+    form.sanitize_control_chars(false);
+    let buf = test_utils::render(&form, 12, 3);
+    test_utils::assert_symbol(&buf, 2, 1, "\x1b");
+}
@@ -0,0 +1,62 @@
+use tui_form_widget::test_utils::{self, FormTester};
+use tui_form_widget::{Form, FormSelection};
+
+fn large_form() -> Form {
+    let names: Vec<String> = (0..500).map(|i| format!("Field {i}")).collect();
+    Form::from(names.iter().map(String::as_str).collect::<Vec<_>>())
+}
+
+#[test]
+fn only_fields_in_view_are_laid_out() {
+    let mut form = large_form();
+    form.select(FormSelection::Hovered(300));
+    let buf = test_utils::render(&form, 20, 9);
+
+    assert_eq!(test_utils::lines(&buf)[6], "╭Field 300─────────╮");
+    assert_eq!(
+        form.field_area(298),
+        Some(ratatui::layout::Rect::new(0, 0, 20, 3))
+    );
+    assert_eq!(form.field_area(0), None);
+    assert_eq!(form.field_area(301), None);
+}
+
+#[test]
+fn the_window_follows_navigation() {
+    let mut form = large_form();
+    form.select(FormSelection::Hovered(0));
+    let tester = FormTester::new(form).keys(&"j".repeat(5));
+    test_utils::render(tester.form(), 20, 9);
+    assert_eq!(tester.form().field_area(5).map(|area| area.y), Some(6));
+
+    // moving back up scrolls only once the selection leaves the window
+    let tester = tester.keys("kk");
+    test_utils::render(tester.form(), 20, 9);
+    assert_eq!(tester.form().field_area(3).map(|area| area.y), Some(0));
+    let tester = tester.keys("k");
+    test_utils::render(tester.form(), 20, 9);
+    assert_eq!(tester.form().field_area(2).map(|area| area.y), Some(0));
+
+    // wrapping to the end jumps the window there
+    let tester = tester.keys("kkk");
+    test_utils::render(tester.form(), 20, 9);
+    assert_eq!(tester.form().field_area(499).map(|area| area.y), Some(6));
+}
+
+#[test]
+fn rendering_never_panics_on_an_odd_selection() {
+    let mut form = Form::from(vec!["Name", "Email"]);
+    form.select(FormSelection::Hovered(5));
+    test_utils::render(&form, 20, 9);
+
+    let mut form = Form::default();
+    form.show_hints(true);
+    form.select(FormSelection::Hovered(0));
+    test_utils::render(&form, 20, 9);
+
+    let mut form = Form::from(vec!["Name", "Email"]);
+    form.select(FormSelection::Hovered(1));
+    form.remove_field("Email").unwrap();
+    form.remove_field("Name").unwrap();
+    test_utils::render(&form, 20, 9);
+}
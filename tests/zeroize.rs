@@ -0,0 +1,112 @@
+//! Checks that secret values aren't left in memory handed back to the allocator, using an
+//! allocator that scans every freed block for them.
+#![cfg(feature = "zeroize")]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crossterm::event::KeyCode;
+use tui_form_widget::{FieldDef, Form, FormSelection};
+
+/// Secrets typed by each test, so tests running in parallel don't see each other's.
+const SECRETS: [&str; 4] = ["Zq1edit", "Zq2reset", "Zq3drop", "Zq4values"];
+
+static FREED: [AtomicBool; 4] = [
+    AtomicBool::new(false),
+    AtomicBool::new(false),
+    AtomicBool::new(false),
+    AtomicBool::new(false),
+];
+
+/// Records which secrets were still in memory when it was freed, in order or reversed.
+struct Scanning;
+
+unsafe impl GlobalAlloc for Scanning {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let block = std::slice::from_raw_parts(ptr, layout.size());
+        for (secret, freed) in SECRETS.iter().zip(&FREED) {
+            // no allocating in here
+            let secret = secret.as_bytes();
+            let reversed = |w: &[u8]| w.iter().eq(secret.iter().rev());
+            if block
+                .windows(secret.len())
+                .any(|w| w == secret || reversed(w))
+            {
+                freed.store(true, Ordering::Relaxed);
+            }
+        }
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Scanning = Scanning;
+
+fn password_form() -> Form {
+    let mut form = Form::from(vec![
+        FieldDef::text("User"),
+        FieldDef::text("Password").secret(),
+    ]);
+    form.select(FormSelection::Active(1));
+    form
+}
+
+fn assert_not_freed(i: usize) {
+    assert!(
+        !FREED[i].load(Ordering::Relaxed),
+        "{:?} was freed without being zeroized",
+        SECRETS[i]
+    );
+}
+
+#[test]
+fn editing_doesnt_leave_copies() {
+    let mut form = password_form();
+    form.type_str(SECRETS[0]);
+    for _ in 0..3 {
+        form.input(KeyCode::Left);
+    }
+    form.type_str("xyz");
+    form.input(KeyCode::Backspace);
+    form.input(KeyCode::Delete);
+    form.input(KeyCode::End);
+    form.input(KeyCode::Esc);
+    drop(form);
+    assert_not_freed(0);
+}
+
+#[test]
+fn reset_zeroizes_secrets() {
+    let mut form = password_form();
+    form.type_str(SECRETS[1]);
+    form.input(KeyCode::Left);
+    form.status();
+    form.reset();
+    assert_eq!(form.status()[1].value(), "");
+    assert_not_freed(1);
+    drop(form);
+}
+
+#[test]
+fn dropping_the_form_zeroizes_secrets() {
+    let mut form = password_form();
+    form.type_str(SECRETS[2]);
+    form.submit();
+    drop(form);
+    assert_not_freed(2);
+}
+
+#[test]
+fn submitted_values_zeroize_secrets() {
+    let mut form = password_form();
+    form.type_str(SECRETS[3]);
+    form.submit();
+    let values = form.into_values();
+    assert_eq!(values[1].value(), SECRETS[3]);
+    drop(values);
+    assert_not_freed(3);
+}